@@ -0,0 +1,44 @@
+// Benchmark of `Display`, e.g. for flags in hot logging paths.
+//
+// Run with `cargo bench --bench display`.
+
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::Instant;
+
+tiny_bit_flags::tiny_bit_flags! {
+    struct Flags: u32 {
+        const F0 = 1 << 0;   const F1 = 1 << 1;   const F2 = 1 << 2;   const F3 = 1 << 3;
+        const F4 = 1 << 4;   const F5 = 1 << 5;   const F6 = 1 << 6;   const F7 = 1 << 7;
+        const F8 = 1 << 8;   const F9 = 1 << 9;   const F10 = 1 << 10; const F11 = 1 << 11;
+        const F12 = 1 << 12; const F13 = 1 << 13; const F14 = 1 << 14; const F15 = 1 << 15;
+        const F16 = 1 << 16; const F17 = 1 << 17; const F18 = 1 << 18; const F19 = 1 << 19;
+        const F20 = 1 << 20; const F21 = 1 << 21; const F22 = 1 << 22; const F23 = 1 << 23;
+        const F24 = 1 << 24; const F25 = 1 << 25; const F26 = 1 << 26; const F27 = 1 << 27;
+        const F28 = 1 << 28; const F29 = 1 << 29; const F30 = 1 << 30; const F31 = 1 << 31;
+    }
+}
+
+fn bench(name: &str, flags: Flags) {
+    const ROUNDS: u32 = 1_000_000;
+    let mut buf = String::with_capacity(512);
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        buf.clear();
+        write!(buf, "{}", black_box(flags)).unwrap();
+        black_box(&buf);
+    }
+    let ns = start.elapsed().as_nanos() / u128::from(ROUNDS);
+    println!("{name:<12} {ns:>6} ns/iter  {buf:.40}");
+}
+
+fn main() {
+    bench("empty", Flags(0));
+    bench("low flag", Flags(Flags::F0));
+    bench("high flag", Flags(Flags::F31));
+    bench(
+        "four flags",
+        Flags(Flags::F1 | Flags::F7 | Flags::F15 | Flags::F23),
+    );
+    bench("all flags", Flags(u32::MAX));
+}
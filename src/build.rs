@@ -0,0 +1,407 @@
+//! Generate [`tiny_bit_flags!`](crate::tiny_bit_flags) invocations from
+//! flag definitions kept outside Rust, in build scripts.
+//!
+//! A JSON spec, e.g. shared with other languages, describes one struct, or
+//! an array of them:
+//!
+//! ```json
+//! {
+//!     "name": "PrimFlags",
+//!     "type": "u32",
+//!     "visibility": "pub",
+//!     "description": "Flags of a memory page.",
+//!     "options": ["serde = \"map\""],
+//!     "flags": [
+//!         { "name": "WRITABLE", "value": 1, "description": "Can be written." },
+//!         { "name": "EXECUTABLE", "value": "0x2" }
+//!     ]
+//! }
+//! ```
+//!
+//! The `visibility`, `description` and `options` fields are optional. A
+//! value is a number, or a string of a Rust expression, e.g. `"1 << 31"`.
+//!
+//! The build script writes the generated code to `OUT_DIR`, with the
+//! `build` feature of tiny-bit-flags in `[build-dependencies]`:
+//!
+//! ```rust,ignore
+//! // build.rs
+//! fn main() {
+//!     println!("cargo::rerun-if-changed=flags.json");
+//!     let spec = std::fs::read_to_string("flags.json").unwrap();
+//!     let code = tiny_bit_flags::build::from_json(&spec).unwrap();
+//!     let out = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("flags.rs");
+//!     std::fs::write(out, code).unwrap();
+//! }
+//! ```
+//!
+//! and the crate includes it:
+//!
+//! ```rust,ignore
+//! include!(concat!(env!("OUT_DIR"), "/flags.rs"));
+//! ```
+//!
+//! The flags can also be read from `#define` constants of a C header, see
+//! [`from_c_header`], so that the values are never copied by hand.
+
+use std::fmt::Write;
+use std::string::String;
+
+use serde_json::Value;
+
+/// Error of an invalid spec, with a message of what's wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecError(String);
+
+impl core::fmt::Display for SpecError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "invalid flags spec: {}", self.0)
+    }
+}
+
+impl core::error::Error for SpecError {}
+
+/// The code of the structs of the JSON spec, one `tiny_bit_flags!`
+/// invocation for each.
+///
+/// ```rust
+/// let code = tiny_bit_flags::build::from_json(r#"{
+///     "name": "PrimFlags",
+///     "type": "u32",
+///     "flags": [
+///         { "name": "WRITABLE", "value": 1, "description": "Can be written." },
+///         { "name": "EXECUTABLE", "value": "0x2" }
+///     ]
+/// }"#).unwrap();
+///
+/// assert_eq!(code, "\
+/// ::tiny_bit_flags::tiny_bit_flags! {
+///     struct PrimFlags: u32 {
+///         /// Can be written.
+///         const WRITABLE = 1;
+///         const EXECUTABLE = 0x2;
+///     }
+/// }
+/// ");
+///
+/// let err = tiny_bit_flags::build::from_json(r#"{ "name": "PrimFlags", "type": "f32", "flags": [] }"#);
+/// assert_eq!(err.unwrap_err().to_string(), "invalid flags spec: `PrimFlags`: unsupported type `f32`");
+/// ```
+pub fn from_json(spec: &str) -> Result<String, SpecError> {
+    let spec: Value = serde_json::from_str(spec).map_err(|err| SpecError(err.to_string()))?;
+    let mut code = String::new();
+    match &spec {
+        Value::Array(structs) => {
+            for s in structs {
+                write_struct(&mut code, s)?;
+            }
+        }
+        s => write_struct(&mut code, s)?,
+    }
+    Ok(code)
+}
+
+/// The code of the struct of the JSON spec, with the flags read from
+/// `#define` constants of a C header instead of the `flags` field.
+///
+/// The spec has a `prefix` field, and each object-like macro whose name
+/// starts with it makes a flag, named without the prefix. A value is a C
+/// integer constant expression, where integer suffixes and outer
+/// parentheses are dropped, octal literals are converted, `~` becomes `!`,
+/// and macros of earlier flags become `Self::` paths. A trailing comment,
+/// or else the comment just above, becomes the description.
+///
+/// The header is not preprocessed, so conditional directives and
+/// `#include`s are ignored. Macros without a value, e.g. include guards,
+/// are skipped.
+///
+/// ```rust
+/// let header = r#"
+/// #ifndef PRIM_H
+/// #define PRIM_H
+///
+/// /* Can be written. */
+/// #define PRIM_WRITABLE   0x01u
+/// #define PRIM_EXECUTABLE 02 // Can be executed.
+/// #define PRIM_ALL        (PRIM_WRITABLE | PRIM_EXECUTABLE)
+///
+/// #endif
+/// "#;
+/// let code = tiny_bit_flags::build::from_c_header(header, r#"{
+///     "name": "PrimFlags",
+///     "type": "u32",
+///     "prefix": "PRIM_"
+/// }"#).unwrap();
+///
+/// assert_eq!(code, "\
+/// ::tiny_bit_flags::tiny_bit_flags! {
+///     struct PrimFlags: u32 {
+///         /// Can be written.
+///         const WRITABLE = 0x01;
+///         /// Can be executed.
+///         const EXECUTABLE = 0o2;
+///         const ALL = Self::WRITABLE | Self::EXECUTABLE;
+///     }
+/// }
+/// ");
+///
+/// let err = tiny_bit_flags::build::from_c_header(
+///     "#define PRIM_MASK (PRIM_OTHER | 1)",
+///     r#"{ "name": "PrimFlags", "type": "u32", "prefix": "PRIM_" }"#,
+/// );
+/// assert_eq!(err.unwrap_err().to_string(),
+///     "invalid flags spec: `PrimFlags`: line 1: unknown `PRIM_OTHER` in value of `PRIM_MASK`");
+/// ```
+pub fn from_c_header(header: &str, spec: &str) -> Result<String, SpecError> {
+    let mut spec: Value = serde_json::from_str(spec).map_err(|err| SpecError(err.to_string()))?;
+    let name = ident(&spec, "struct")?;
+    let err = |msg: String| SpecError(format!("`{name}`: {msg}"));
+    let prefix = str_field(&spec, "prefix").ok_or_else(|| err("missing `prefix`".into()))?;
+
+    let flags = header_flags(header, prefix).map_err(err)?;
+    match spec.as_object_mut() {
+        Some(object) => object.insert("flags".into(), Value::Array(flags)),
+        None => return Err(SpecError("the spec is not an object".into())),
+    };
+
+    let mut code = String::new();
+    write_struct(&mut code, &spec)?;
+    Ok(code)
+}
+
+// the flags of the `#define`s with the prefix, as in the JSON spec
+fn header_flags(header: &str, prefix: &str) -> Result<Vec<Value>, String> {
+    let header = header.replace("\\\r\n", " ").replace("\\\n", " ");
+    let mut flags = Vec::new();
+    let mut names = Vec::new();
+    let mut comment: Vec<&str> = Vec::new();
+    let mut in_comment = false;
+
+    for (n, line) in header.lines().enumerate() {
+        let line = line.trim();
+        if in_comment {
+            let (text, end) = match line.split_once("*/") {
+                Some((text, _)) => (text, true),
+                None => (line, false),
+            };
+            comment.push(text.trim_start_matches('*').trim());
+            in_comment = !end;
+            continue;
+        }
+        if let Some(text) = line.strip_prefix("//") {
+            comment.push(text.trim_start_matches('/').trim());
+            continue;
+        }
+        if let Some(text) = line.strip_prefix("/*") {
+            let text = text.trim_start_matches('*');
+            match text.split_once("*/") {
+                Some((text, _)) => comment.push(text.trim()),
+                None => {
+                    comment.push(text.trim());
+                    in_comment = true;
+                }
+            }
+            continue;
+        }
+        let above = core::mem::take(&mut comment);
+
+        let Some(define) = line
+            .strip_prefix('#')
+            .and_then(|l| l.trim_start().strip_prefix("define"))
+            .filter(|l| l.starts_with([' ', '\t']))
+        else {
+            continue;
+        };
+        let define = define.trim_start();
+        let end = define
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            .unwrap_or(define.len());
+        let (macro_name, rest) = define.split_at(end);
+        // skip function-like macros and other prefixes
+        if rest.starts_with('(') {
+            continue;
+        }
+        let Some(flag) = macro_name.strip_prefix(prefix).filter(|f| !f.is_empty()) else {
+            continue;
+        };
+
+        let (value, trailing) = match rest.find("/*").into_iter().chain(rest.find("//")).min() {
+            Some(i) => {
+                let text = rest[i + 2..].trim_start_matches(['*', '/', '<']);
+                (
+                    &rest[..i],
+                    Some(text.split("*/").next().unwrap_or("").trim()),
+                )
+            }
+            None => (rest, None),
+        };
+        let value = value.trim();
+        if value.is_empty() {
+            continue;
+        }
+        let value = c_expr(value, prefix, &names)
+            .map_err(|msg| format!("line {}: {msg} in value of `{macro_name}`", n + 1))?;
+
+        let mut object = serde_json::Map::new();
+        object.insert("name".into(), flag.into());
+        object.insert("value".into(), value.into());
+        let description = match trailing {
+            Some(text) => text.to_string(),
+            None => above.join("\n").trim().to_string(),
+        };
+        if !description.is_empty() {
+            object.insert("description".into(), description.into());
+        }
+        flags.push(Value::Object(object));
+        names.push(flag);
+    }
+    Ok(flags)
+}
+
+// the Rust expression of a C integer constant expression
+fn c_expr(value: &str, prefix: &str, names: &[&str]) -> Result<String, String> {
+    let mut expr = String::new();
+    let mut chars = value.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            let mut end = i + c.len_utf8();
+            while let Some(&(j, c)) = chars.peek() {
+                if !c.is_ascii_alphanumeric() && c != '_' {
+                    break;
+                }
+                end = j + c.len_utf8();
+                chars.next();
+            }
+            let token = &value[i..end];
+            if c.is_ascii_digit() {
+                expr.push_str(&c_number(token)?);
+            } else {
+                match token.strip_prefix(prefix).filter(|f| names.contains(f)) {
+                    Some(flag) => {
+                        let _ = write!(expr, "Self::{flag}");
+                    }
+                    None => return Err(format!("unknown `{token}`")),
+                }
+            }
+        } else if c == '~' {
+            expr.push('!');
+        } else if "|&^<>()+-*/% \t".contains(c) {
+            expr.push(c);
+        } else {
+            return Err(format!("unsupported `{c}`"));
+        }
+    }
+
+    let mut expr = expr.split_whitespace().collect::<Vec<_>>().join(" ");
+    // the parentheses around the whole value are unused in Rust
+    while let Some(inner) = expr.strip_prefix('(').and_then(|e| e.strip_suffix(')')) {
+        let mut depth = 0;
+        let balanced = inner.chars().all(|c| {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                _ => {}
+            }
+            depth >= 0
+        });
+        if !balanced {
+            break;
+        }
+        expr = inner.trim().to_string();
+    }
+    Ok(expr)
+}
+
+fn c_number(token: &str) -> Result<String, String> {
+    let digits = token.trim_end_matches(['u', 'U', 'l', 'L']);
+    let lower = digits.to_ascii_lowercase();
+    let valid = |s: &str, radix| !s.is_empty() && s.chars().all(|c| c.is_digit(radix));
+    let number = if let Some(hex) = lower.strip_prefix("0x") {
+        valid(hex, 16).then(|| format!("0x{hex}"))
+    } else if let Some(bin) = lower.strip_prefix("0b") {
+        valid(bin, 2).then(|| format!("0b{bin}"))
+    } else if let Some(oct) = lower.strip_prefix('0').filter(|o| !o.is_empty()) {
+        valid(oct, 8).then(|| format!("0o{oct}"))
+    } else {
+        valid(&lower, 10).then_some(lower.clone())
+    };
+    number.ok_or_else(|| format!("invalid number `{token}`"))
+}
+
+const TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+fn write_struct(code: &mut String, spec: &Value) -> Result<(), SpecError> {
+    let name = ident(spec, "struct")?;
+    let err = |msg: String| SpecError(format!("`{name}`: {msg}"));
+    let ty = str_field(spec, "type").ok_or_else(|| err("missing `type`".into()))?;
+    if !TYPES.contains(&ty) {
+        return Err(err(format!("unsupported type `{ty}`")));
+    }
+    let flags = spec["flags"]
+        .as_array()
+        .ok_or_else(|| err("missing `flags`".into()))?;
+
+    // a `String` never fails to write
+    let _ = writeln!(code, "::tiny_bit_flags::tiny_bit_flags! {{");
+    write_doc(code, "    ", str_field(spec, "description"));
+    match &spec["options"] {
+        Value::Null => {}
+        Value::Array(options) => {
+            let options: Option<Vec<&str>> = options.iter().map(Value::as_str).collect();
+            let options = options.ok_or_else(|| err("an option is not a string".into()))?;
+            let _ = writeln!(code, "    #[tiny_bit_flags({})]", options.join(", "));
+        }
+        _ => return Err(err("`options` is not an array".into())),
+    }
+    let vis = match str_field(spec, "visibility") {
+        Some(vis) => format!("{vis} "),
+        None => String::new(),
+    };
+    let _ = writeln!(code, "    {vis}struct {name}: {ty} {{");
+    for flag in flags {
+        let flag_name = ident(flag, "flag")?;
+        let value = match &flag["value"] {
+            Value::Number(n) => n.to_string(),
+            Value::String(expr) => expr.clone(),
+            _ => return Err(err(format!("missing `value` of `{flag_name}`"))),
+        };
+        write_doc(code, "        ", str_field(flag, "description"));
+        let _ = writeln!(code, "        const {flag_name} = {value};");
+    }
+    let _ = writeln!(code, "    }}");
+    let _ = writeln!(code, "}}");
+    Ok(())
+}
+
+// the `name` field, if it's an identifier
+fn ident<'a>(spec: &'a Value, what: &str) -> Result<&'a str, SpecError> {
+    let name =
+        str_field(spec, "name").ok_or_else(|| SpecError(format!("missing name of {what}")))?;
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        return Err(SpecError(format!(
+            "{what} name `{name}` is not an identifier"
+        )));
+    }
+    Ok(name)
+}
+
+fn str_field<'a>(spec: &'a Value, key: &str) -> Option<&'a str> {
+    spec.get(key).and_then(Value::as_str)
+}
+
+fn write_doc(code: &mut String, indent: &str, doc: Option<&str>) {
+    for line in doc.into_iter().flat_map(str::lines) {
+        let _ = writeln!(
+            code,
+            "{indent}///{}{line}",
+            if line.is_empty() { "" } else { " " }
+        );
+    }
+}
@@ -4,12 +4,11 @@
 //!
 //! # Usage
 //!
-//! Import this crate and `paste` to your `Cargo.toml`:
+//! Import this crate to your `Cargo.toml`:
 //!
 //! ```toml
 //! [dependencies]
 //! tiny-bit-flags = "0.1"
-//! paste = "1.0"
 //! ```
 //!
 //! Invoke the `tiny_bit_flags!` macro to define flags:
@@ -28,21 +27,100 @@
 //!
 //! ```rust,ignore
 //! // struct
+//! #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 //! struct PrimFlags(u32);
 //!
 //! impl PrimFlags {
 //!     // constant values
 //!     const WRITABLE: u32   = 0b00000001;
 //!     const EXECUTABLE: u32 = 0b00000010;
+//!     // names of flags, in declaration order
+//!     const FLAG_NAMES: &'static [&'static str] = &["WRITABLE", "EXECUTABLE"];
+//!     // values of flags, in declaration order
+//!     const FLAG_VALUES: &'static [u32] = &[0b00000001, 0b00000010];
+//!     // doc comments of flags, in declaration order
+//!     const FLAG_DESCRIPTIONS: &'static [&'static str] = &["", ""];
+//!     // number of flags
+//!     const NUM_FLAGS: usize = 2;
+//!     // the longest output of `Display`
+//!     const MAX_DISPLAY_LEN: usize = 34;
 //!     // checking methods
 //!     const fn is_writable(&self) -> bool { ... }
 //!     const fn is_executable(&self) -> bool { ... }
+//!     // checking functions on raw bits
+//!     const fn is_writable_in(bits: u32) -> bool { ... }
+//!     const fn is_executable_in(bits: u32) -> bool { ... }
 //!     // setting methods
 //!     const fn set_writable(&mut self) { ... }
 //!     const fn set_executable(&mut self) { ... }
 //!     // clearing methods
 //!     const fn clear_writable(&mut self) { ... }
 //!     const fn clear_executable(&mut self) { ... }
+//!     // name of the flag, if the value is exactly one flag
+//!     const fn name(&self) -> Option<&'static str> { ... }
+//!     // doc comment of the flag, if the value is exactly one flag
+//!     const fn description(&self) -> Option<&'static str> { ... }
+//!     // the bit at the position, e.g. for values of flags; panics, or fails
+//!     // to compile in a const, if `n` is out of the type
+//!     const fn bit(n: u32) -> u32 { ... }
+//!     // position of the bit, if the value is exactly one bit
+//!     const fn bit_index(&self) -> Option<u32> { ... }
+//!     // the flag of the name
+//!     const fn from_name(name: &str) -> Option<Self> { ... }
+//!     // access by bit position, `None` or `false` if not of a declared flag
+//!     const fn test_bit(&self, n: u32) -> Option<bool> { ... }
+//!     const fn insert_bit(&mut self, n: u32) -> bool { ... }
+//!     const fn remove_bit(&mut self, n: u32) -> bool { ... }
+//!     // exchange the states of the flags
+//!     const fn swap_flags(&mut self, a: Self, b: Self) { ... }
+//!     // overwrite the bits of `mask` with those of `from`
+//!     const fn copy_masked(&mut self, from: Self, mask: Self) { ... }
+//!     // whether all bits of `include` are set and none of `exclude`
+//!     const fn matches(self, include: Self, exclude: Self) -> bool { ... }
+//!     // the bits of `required` that are not set, if any
+//!     const fn require(&self, required: Self) -> Result<(), Self> { ... }
+//!     // the bits of declared flags, and the other bits
+//!     const fn split_known(self) -> (Self, u32) { ... }
+//!     // equality without branches on the bits, against timing attacks
+//!     fn ct_eq(&self, other: &Self) -> bool { ... }
+//!     // whether each flag is set, in declaration order
+//!     const fn to_bools(&self) -> [bool; 2] { ... }
+//!     const fn from_bools(bools: [bool; 2]) -> Self { ... }
+//!     // union of all the values
+//!     const fn union_all(flags: &[Self]) -> Self { ... }
+//!     // number of the values with each flag set, and with the flag set
+//!     const fn count_each(flags: &[Self]) -> [usize; 2] { ... }
+//!     const fn count_with(flags: &[Self], flag: Self) -> usize { ... }
+//!     // whether any or all of the values, or which, have all bits of the mask
+//!     fn any_set_in(flags: &[Self], mask: Self) -> bool { ... }
+//!     fn all_set_in(flags: &[Self], mask: Self) -> bool { ... }
+//!     fn filter_indices(flags: &[Self], mask: Self) -> impl Iterator<Item = usize> { ... }
+//!     // embed in a larger word at the bit offset, and get out of it, or
+//!     // `None` if the bits don't fit in the word
+//!     const fn pack_into(self, word: u64, offset: u32) -> Option<u64> { ... }
+//!     const fn extract_from(word: u64, offset: u32) -> Option<Self> { ... }
+//!     // the change between the values, e.g. `+WRITABLE -EXECUTABLE`
+//!     fn format_changes(old: Self, new: Self) -> impl Display { ... }
+//!     // access by a pointer that may be unaligned, e.g. in a packed struct
+//!     const unsafe fn read_packed(ptr: *const Self) -> Self { ... }
+//!     const unsafe fn write_packed(ptr: *mut Self, flags: Self) { ... }
+//!     unsafe fn update_packed<R>(ptr: *mut Self, f: impl FnOnce(&mut Self) -> R) -> R { ... }
+//!     // the flags of the same names in another layout
+//!     fn migrate_from<F: Flags>(other: &F) -> Result<Self, UnknownBitsError> { ... }
+//!     // the flags set to `true`, by names
+//!     fn from_pairs<I: IntoIterator<Item = (S, bool)>, S: AsRef<str>>(pairs: I) -> Result<Self, PairError> { ... }
+//!     // changes by `+` or `-` and names, e.g. `+WRITABLE,-EXECUTABLE`
+//!     fn apply_str(&mut self, s: &str) -> Result<(), ParseError> { ... }
+//!     // iterator over all flags, in declaration order
+//!     fn all_flags() -> impl Iterator<Item = Self> { ... }
+//!     // iterator over all combinations of flags, or `None` if more than 20
+//!     fn power_set() -> Option<impl Iterator<Item = Self>> { ... }
+//!     // the output of `Display` without padding
+//!     fn write_names(&self, f: &mut impl Write) -> fmt::Result { ... }
+//!     // iterator over names of the set flags, in declaration order
+//!     fn iter_names(&self) -> impl Iterator<Item = &'static str> { ... }
+//!     // iterator over positions of the set bits of declared flags
+//!     fn iter_bit_indices(&self) -> impl Iterator<Item = u32> { ... }
 //! }
 //! ```
 //!
@@ -58,6 +136,318 @@
 //!
 //! f.set_executable(); // set flag
 //! assert!(f.is_executable());
+//!
+//! assert!(PrimFlags::is_writable_in(0b01)); // check raw bits
+//!
+//! let bits = [false; PrimFlags::NUM_FLAGS]; // one slot per flag
+//! ```
+//!
+//! The other generated methods, in the order above, work as follows, with
+//! the `PrimFlags` above.
+//!
+//! `is_writable_in()` and the like check raw bits, and `NUM_FLAGS` sizes
+//! arrays of one slot per flag:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! assert!(PrimFlags::is_executable_in(0b10) && !PrimFlags::is_writable_in(0b10));
+//! let bits = [false; PrimFlags::NUM_FLAGS];
+//! assert_eq!(bits.len(), 2);
+//! ```
+//!
+//! `name()` and `from_name()` map a single flag to its name and back, also
+//! in constants:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! assert_eq!(PrimFlags(PrimFlags::EXECUTABLE).name(), Some("EXECUTABLE"));
+//! assert!(PrimFlags::from_name("READABLE").is_none());
+//!
+//! const W: Option<PrimFlags> = PrimFlags::from_name("WRITABLE");
+//! assert!(W.unwrap().is_writable());
+//! ```
+//!
+//! `bit_index()`, `test_bit()` and `insert_bit()` work by bit positions,
+//! only of declared flags:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! let f = PrimFlags(PrimFlags::EXECUTABLE);
+//! assert_eq!(f.bit_index(), Some(1));
+//! assert_eq!(f.test_bit(1), Some(true));
+//! assert_eq!(f.test_bit(2), None); // not a declared flag
+//!
+//! let mut g = PrimFlags(0);
+//! assert!(g.insert_bit(0) && g.is_writable());
+//! assert!(!g.insert_bit(40)); // out of range
+//! ```
+//!
+//! `swap_flags()` and `copy_masked()` move states between flags and values:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! let mut f = PrimFlags(PrimFlags::EXECUTABLE);
+//! f.swap_flags(PrimFlags(PrimFlags::WRITABLE), PrimFlags(PrimFlags::EXECUTABLE));
+//! assert!(f.is_writable() && !f.is_executable());
+//!
+//! let mut local = PrimFlags(0x100 | PrimFlags::WRITABLE);
+//! local.copy_masked(PrimFlags(PrimFlags::EXECUTABLE), PrimFlags(0b11));
+//! assert_eq!(local.0, 0x100 | PrimFlags::EXECUTABLE);
+//! ```
+//!
+//! `matches()` checks included and excluded flags at once:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! let (w, x) = (PrimFlags(PrimFlags::WRITABLE), PrimFlags(PrimFlags::EXECUTABLE));
+//! assert!(x.matches(x, w));
+//! assert!(!PrimFlags(0b11).matches(x, w));
+//! assert!(!PrimFlags(0).matches(x, PrimFlags(0)));
+//! ```
+//!
+//! `require()` tells the missing flags, and `split_known()` the unknown
+//! bits:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! let f = PrimFlags(PrimFlags::EXECUTABLE);
+//! assert!(f.require(PrimFlags(PrimFlags::EXECUTABLE)).is_ok());
+//! let missing = f.require(PrimFlags(0b11)).unwrap_err();
+//! assert_eq!(missing.to_string(), "WRITABLE");
+//!
+//! let (known, unknown) = PrimFlags(0x8001).split_known();
+//! assert_eq!((known.0, unknown), (PrimFlags::WRITABLE, 0x8000));
+//! ```
+//!
+//! `ct_eq()` compares without branches on the bits:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! let f = PrimFlags(PrimFlags::EXECUTABLE);
+//! assert!(f.ct_eq(&PrimFlags(PrimFlags::EXECUTABLE)) && !f.ct_eq(&PrimFlags(0b11)));
+//! ```
+//!
+//! `to_bools()` and `from_bools()` convert to and from one `bool` per
+//! flag:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! assert_eq!(PrimFlags(PrimFlags::EXECUTABLE).to_bools(), [false, true]);
+//! assert!(PrimFlags::from_bools([true, false]).is_writable());
+//! ```
+//!
+//! `union_all()`, `count_each()`, `count_with()`, `any_set_in()`,
+//! `all_set_in()` and `filter_indices()` work over slices of values, e.g.
+//! of records:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! const BOTH: PrimFlags = PrimFlags::union_all(&[PrimFlags(1), PrimFlags(2)]);
+//! assert_eq!(BOTH.0, 0b11);
+//!
+//! let records = [PrimFlags(0b01), PrimFlags(0b11), PrimFlags(0)];
+//! assert_eq!(PrimFlags::count_each(&records), [2, 1]);
+//! assert_eq!(PrimFlags::count_with(&records, PrimFlags(PrimFlags::EXECUTABLE)), 1);
+//! assert!(PrimFlags::any_set_in(&records, PrimFlags(0b11)));
+//! assert!(!PrimFlags::all_set_in(&records, PrimFlags(0b01)));
+//! let indices = PrimFlags::filter_indices(&records, PrimFlags(0b01));
+//! assert_eq!(indices.collect::<Vec<_>>(), [0, 1]);
+//! ```
+//!
+//! `pack_into()` and `extract_from()` embed the bits in a larger word, or
+//! return `None` if they don't fit:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! let f = PrimFlags(0b11);
+//! assert_eq!(f.pack_into(0xf000_0000_0000_0000, 12), Some(0xf000_0000_0000_3000));
+//! assert_eq!(PrimFlags::extract_from(0x3000, 12).unwrap().0, 0b11);
+//! assert_eq!(f.pack_into(0, 33), None); // out of the word
+//! assert!(PrimFlags::extract_from(0, u32::MAX).is_none());
+//! ```
+//!
+//! `format_changes()` writes the change between two values:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! let changes = PrimFlags::format_changes(PrimFlags(0b10), PrimFlags(0b01));
+//! assert_eq!(changes.to_string(), "+WRITABLE -EXECUTABLE");
+//! ```
+//!
+//! `from_pairs()` takes the flags set to `true`, e.g. of toggles in a
+//! config map, and fails with the index of a pair of an unknown name:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! let toggles = std::collections::HashMap::from([("WRITABLE".to_string(), true)]);
+//! assert!(PrimFlags::from_pairs(toggles).unwrap().is_writable());
+//! assert_eq!(PrimFlags::from_pairs([("EXECUTABLE", false)]).unwrap().0, 0);
+//!
+//! let pairs = [("WRITABLE", true), ("READABLE", true)];
+//! let err = PrimFlags::from_pairs(pairs).map(|f| f.0).unwrap_err();
+//! assert_eq!((err.index(), err.error().span()), (1, 0..8));
+//! ```
+//!
+//! `apply_str()` tweaks the value by `+` and `-` changes, or fails with the
+//! span of the invalid name, leaving the value unchanged:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! let mut f = PrimFlags(PrimFlags::EXECUTABLE);
+//! f.apply_str("+WRITABLE, -EXECUTABLE").unwrap();
+//! assert!(f.is_writable() && !f.is_executable());
+//!
+//! let err = f.apply_str("-WRITABLE,READABLE").unwrap_err();
+//! assert_eq!(err.span(), 10..18);
+//! assert!(f.is_writable()); // unchanged on an error
+//! let err = f.apply_str("+ WRITABLE | READABLE").unwrap_err();
+//! assert_eq!(err.span(), 13..21); // the name, not the `+`
+//! let err = f.apply_str("+,-WRITABLE").unwrap_err();
+//! assert_eq!(err.span(), 1..1); // a missing name
+//! ```
+//!
+//! `all_flags()` and `power_set()` iterate over the flags, and over all
+//! their combinations, e.g. for exhaustive tests:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! assert_eq!(PrimFlags::all_flags().filter(|f| f.is_writable()).count(), 1);
+//! let all = PrimFlags::power_set().unwrap().map(|f| f.0);
+//! assert_eq!(all.collect::<Vec<_>>(), [0, 1, 2, 3]);
+//! ```
+//!
+//! `power_set()` returns `None` for more than 20 flags, instead of
+//! iterating over millions of values:
+//!
+//! ```rust
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     struct ManyFlags: u32 {
+//!         const F0  = 1 << 0;
+//!         const F1  = 1 << 1;
+//!         const F2  = 1 << 2;
+//!         const F3  = 1 << 3;
+//!         const F4  = 1 << 4;
+//!         const F5  = 1 << 5;
+//!         const F6  = 1 << 6;
+//!         const F7  = 1 << 7;
+//!         const F8  = 1 << 8;
+//!         const F9  = 1 << 9;
+//!         const F10 = 1 << 10;
+//!         const F11 = 1 << 11;
+//!         const F12 = 1 << 12;
+//!         const F13 = 1 << 13;
+//!         const F14 = 1 << 14;
+//!         const F15 = 1 << 15;
+//!         const F16 = 1 << 16;
+//!         const F17 = 1 << 17;
+//!         const F18 = 1 << 18;
+//!         const F19 = 1 << 19;
+//!         const F20 = 1 << 20;
+//!     }
+//! }
+//!
+//! assert!(ManyFlags::power_set().is_none());
+//! ```
+//!
+//! `write_names()` writes the output of `Display`, which is at most
+//! `MAX_DISPLAY_LEN` bytes, e.g. into a buffer allocated once:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! let mut buf = String::with_capacity(PrimFlags::MAX_DISPLAY_LEN);
+//! PrimFlags(!0).write_names(&mut buf).unwrap();
+//! assert_eq!(buf, "WRITABLE | EXECUTABLE | 0xfffffffc");
+//! assert_eq!(buf.len(), PrimFlags::MAX_DISPLAY_LEN);
+//! ```
+//!
+//! `iter_names()` and `iter_bit_indices()` iterate over the set flags:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! let f = PrimFlags(PrimFlags::EXECUTABLE);
+//! assert_eq!(f.iter_names().collect::<Vec<_>>(), ["EXECUTABLE"]);
+//! let indices = PrimFlags(0b10011).iter_bit_indices();
+//! assert_eq!(indices.collect::<Vec<_>>(), [0, 1]);
 //! ```
 //!
 //! You can use `pub` before `struct` to make all above to be public:
@@ -68,73 +458,6376 @@
 //! -    struct PrimFlags: u32 {
 //! ```
 //!
-//! You can also derive some traits on the struct:
+//! Doc comments of flags are kept as their descriptions, for help texts
+//! and tooltips. The lines are joined into one, with surrounding spaces
+//! trimmed:
+//!
+//! ```rust
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     struct PrimFlags: u32 {
+//!         /// Allow writing to the object.
+//!         /// Needs the write lock.
+//!         const WRITABLE   = 0b00000001;
+//!         const EXECUTABLE = 0b00000010;
+//!     }
+//! }
+//!
+//! let f = PrimFlags(PrimFlags::WRITABLE);
+//! assert_eq!(f.description(), Some("Allow writing to the object. Needs the write lock."));
+//! assert_eq!(PrimFlags::FLAG_DESCRIPTIONS[1], "");
+//! ```
+//!
+//! A flag can also have its own visibility, for its constant and methods,
+//! to keep internal bits out of the public API. Flags without a visibility
+//! take the struct's one, so use `pub(self)` for a private flag in a `pub`
+//! struct. The flag is still listed in `FLAG_NAMES` and `FLAG_VALUES`:
+//!
+//! ```diff
+//!      pub struct PrimFlags: u32 {
+//!          const WRITABLE   = 0b00000001;
+//!          const EXECUTABLE = 0b00000010;
+//! +        pub(crate) const DIRTY = 0b10000000;
+//! ```
+//!
+//! Flags can also end with `,` instead of `;`, and `const` can be left out,
+//! as in C enums and lists copied from other crates. It's the same as the
+//! form above:
+//!
+//! ```rust
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     struct PrimFlags: u32 {
+//!         WRITABLE   = 1 << 0,
+//!         EXECUTABLE = 1 << 1,
+//!     }
+//! }
+//!
+//! assert_eq!(PrimFlags::FLAG_NAMES, ["WRITABLE", "EXECUTABLE"]);
+//! assert!(PrimFlags(PrimFlags::EXECUTABLE).is_executable());
+//! ```
+//!
+//! A flag can have several bits, e.g. an alias of others. It's set only if
+//! all of its bits are, by every method and format:
+//!
+//! ```rust
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     struct PrimFlags: u32 {
+//!         const WRITABLE   = 0b00000001;
+//!         const EXECUTABLE = 0b00000010;
+//!         const ALL        = 0b00000011;
+//!     }
+//! }
+//!
+//! let half = PrimFlags(PrimFlags::WRITABLE);
+//! assert!(!half.is_all());
+//! assert_eq!(half.to_bools(), [true, false, false]);
+//! assert!(half.iter_names().eq(["WRITABLE"]));
+//! assert_eq!(half.to_string(), "WRITABLE");
+//! assert_eq!(PrimFlags::count_each(&[half]), [1, 0, 0]);
+//!
+//! let full = PrimFlags(PrimFlags::ALL);
+//! assert_eq!(full.to_bools(), [true, true, true]);
+//! assert!(full.iter_names().eq(["WRITABLE", "EXECUTABLE", "ALL"]));
+//! ```
+//!
+//! A flag must have at least one bit. A zero value is a compile error,
+//! because its `is_` method would always return false and its `set_`
+//! method would do nothing:
+//!
+//! ```rust,compile_fail
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     struct PrimFlags: u32 {
+//!         const WRITABLE    = 0b00000001;
+//!         const PLACEHOLDER = 0;
+//!     }
+//! }
+//! ```
+//!
+//! Except a flag named `NONE`, which must be zero: it's not a flag but the
+//! name of the empty value. It's left out of `FLAG_NAMES` and the other
+//! lists, and has `is_none()`, for no flags set, instead of the per-flag
+//! methods:
+//!
+//! ```rust
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     struct PrimFlags: u32 {
+//!         const NONE       = 0;
+//!         const WRITABLE   = 0b00000001;
+//!         const EXECUTABLE = 0b00000010;
+//!     }
+//! }
+//!
+//! assert!(PrimFlags(PrimFlags::NONE).is_none());
+//! assert!(!PrimFlags(PrimFlags::WRITABLE).is_none());
+//! assert_eq!(PrimFlags::FLAG_NAMES, ["WRITABLE", "EXECUTABLE"]);
+//! ```
+//!
+//! The struct derives `Clone`, `Copy`, `PartialEq`, `Eq` and `Hash`, unless
+//! the `no_derives` option is given. You can also derive other traits on it.
+//! Listing these ones again is allowed:
+//!
+//! ```diff
+//!  tiny_bit_flags! {
+//! +    #[derive(Debug, Default)]
+//!      struct PrimFlags: u32 {
+//! ```
+//!
+//! The generated code refers to everything by `::core` and `$crate` paths,
+//! so it doesn't depend on the names in scope, and works in a module with
+//! its own `Option` or `paste`, or with `#[no_implicit_prelude]`:
+//!
+//! ```rust
+//! #[no_implicit_prelude]
+//! mod flags {
+//!     ::tiny_bit_flags::tiny_bit_flags! {
+//!         pub struct PrimFlags: u32 {
+//!             const WRITABLE   = 0b00000001;
+//!             const EXECUTABLE = 0b00000010;
+//!         }
+//!     }
+//! }
+//!
+//! assert!(flags::PrimFlags(0b01).is_writable());
+//! ```
+//!
+//! On nightly, deriving `ConstParamTy` this way makes the struct a type of
+//! const generic parameters, by the `adt_const_params` feature, so code can
+//! be monomorphized on a set of flags known at compile time:
+//!
+//! ```rust,ignore
+//! #![feature(adt_const_params)]
+//!
+//! tiny_bit_flags! {
+//!     #[derive(core::marker::ConstParamTy)]
+//!     struct PrimFlags: u32 {
+//!         const WRITABLE   = 0b00000001;
+//!         const EXECUTABLE = 0b00000010;
+//!     }
+//! }
+//!
+//! fn step<const FLAGS: PrimFlags>() {
+//!     if FLAGS.is_writable() { // resolved at compile time
+//!         // ...
+//!     }
+//! }
+//!
+//! step::<{ PrimFlags(PrimFlags::WRITABLE) }>();
+//! ```
+//!
+//! The outer attributes, with helper ones, go to the struct as written, so
+//! the derives of database crates for newtypes store it as its inner type,
+//! e.g. by sqlx and by diesel-derive-newtype. The column type is theirs to
+//! pick for each backend, e.g. a signed inner type for Postgres, which has
+//! no unsigned integers:
+//!
+//! ```rust,ignore
+//! tiny_bit_flags! {
+//!     #[derive(sqlx::Type, diesel_derive_newtype::DieselNewType)]
+//!     #[sqlx(transparent)]
+//!     struct PrimFlags: i32 {
+//!         const WRITABLE   = 0b00000001;
+//!         const EXECUTABLE = 0b00000010;
+//!     }
+//! }
+//!
+//! // sqlx
+//! let flags: PrimFlags = sqlx::query_scalar("SELECT flags FROM prims")
+//!     .fetch_one(&pool)
+//!     .await?;
+//!
+//! // diesel, with `flags -> Integer` in the `table!` of `prims`
+//! let flags: PrimFlags = prims::table.select(prims::flags).first(&mut conn)?;
+//! ```
+//!
+//! The inner type can be a signed integer too. All methods work on its
+//! bits in two's complement, exactly as on the unsigned type of the same
+//! width, so the sign bit is an ordinary flag. Write its value as `1 << 31`
+//! or `i32::MIN`, because a literal like `0x8000_0000` does not fit in `i32`:
+//!
+//! ```rust
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     struct SignedFlags: i32 {
+//!         const LOW  = 1;
+//!         const SIGN = 1 << 31;
+//!     }
+//! }
+//!
+//! let mut f = SignedFlags(-1); // all bits set
+//! f.clear_low();
+//! assert_eq!(f.0, -2); // only the LOW bit is cleared
+//! assert!(f.is_sign());
+//! f.clear_sign();
+//! assert_eq!(f.0, i32::MAX - 1);
+//! ```
+//!
+//! The bits not of any declared flag are kept as they are. No method
+//! changes them except by an explicit new value, e.g. `from_bools()`, so
+//! a field can carry bits defined by a newer peer through this version:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! let mut f = PrimFlags(0xf0f0_0000 | PrimFlags::WRITABLE);
+//! f.clear_writable();
+//! f.set_executable();
+//! f.clear_executable();
+//! assert!(!f.insert_bit(20) && !f.remove_bit(20)); // not a declared flag
+//! assert_eq!(f.0, 0xf0f0_0000);
+//! assert_eq!(f.to_string().parse::<PrimFlags>().unwrap().0, 0xf0f0_0000);
+//! ```
+//!
+//! A flag can be the union of others, e.g. as a shorthand. It's written,
+//! by `Display` and `format_changes()`, only if not all of its bits are
+//! written by earlier flags:
+//!
+//! ```rust
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     struct AliasFlags: u8 {
+//!         const A  = 0b01;
+//!         const B  = 0b10;
+//!         const AB = 0b11;
+//!     }
+//! }
+//!
+//! assert_eq!(AliasFlags(0b11).to_string(), "A | B");
+//! let changes = AliasFlags::format_changes(AliasFlags(0), AliasFlags(0b11));
+//! assert_eq!(changes.to_string(), "+A +B");
+//! let changes = AliasFlags::format_changes(AliasFlags(0b11), AliasFlags(0b10));
+//! assert_eq!(changes.to_string(), "-A");
+//! ```
+//!
+//! A field of a `#[repr(packed)]` struct can't be borrowed, so the
+//! methods taking `&mut self` can't be called on it in place. Update it by
+//! a raw pointer with `update_packed()`, or read and write it with
+//! `read_packed()` and `write_packed()`. These are unsafe, and the pointer
+//! must be valid as for [`core::ptr::read_unaligned`] and
+//! [`core::ptr::write_unaligned`], as it is from `&raw mut` of the field:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! #[repr(C, packed)]
+//! struct Header {
+//!     version: u8,
+//!     flags: PrimFlags,
+//! }
+//!
+//! let mut header = Header { version: 1, flags: PrimFlags(0) };
+//! // SAFETY: the pointer is of a live field
+//! unsafe { PrimFlags::update_packed(&raw mut header.flags, |f| f.set_executable()) };
+//! assert!(unsafe { PrimFlags::read_packed(&raw const header.flags) }.is_executable());
+//! ```
+//!
+//! Use [`for_each_flag!`] to expand a callback macro once for each
+//! declared flag, in declaration order, with the struct and the flag name
+//! as idents and the value as a constant expression. It's expanded at
+//! compile time, so the callback can generate items, e.g. an FFI shim per
+//! flag, as well as statements. It must come after the declaration, in
+//! the same module or a child one, as the macros by `macro_rules!`:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! // a constant of the name and the value per flag
+//! macro_rules! entry {
+//!     ($BitFlags:ident, $Flag:ident, $value:expr) => {
+//!         const $Flag: (&str, u32) = (stringify!($Flag), $value);
+//!     };
+//! }
+//! tiny_bit_flags::for_each_flag!(PrimFlags, entry);
+//! assert_eq!(EXECUTABLE, ("EXECUTABLE", 0b10));
+//!
+//! // statements, also in const contexts
+//! const fn all_bits() -> u32 {
+//!     let mut bits = 0;
+//!     macro_rules! add {
+//!         ($BitFlags:ident, $Flag:ident, $value:expr) => {
+//!             bits |= $value;
+//!         };
+//!     }
+//!     tiny_bit_flags::for_each_flag!(PrimFlags, add);
+//!     bits
+//! }
+//! assert_eq!(all_bits(), 0b11);
+//! ```
+//!
+//! Use [`flags!`] to write a value by the names of its flags, e.g. in a
+//! `static` or a match guard. It's evaluated at compile time, so an
+//! unknown name is a compile error:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! use tiny_bit_flags::flags;
+//!
+//! let f = flags!(PrimFlags: WRITABLE | EXECUTABLE); // PrimFlags(0b11)
+//! assert!(f.is_writable() && f.is_executable());
+//! match f {
+//!     f if f.matches(flags!(PrimFlags: WRITABLE), flags!(PrimFlags:)) => {}
+//!     _ => unreachable!(),
+//! }
+//! ```
+//!
+//! The struct also implements `Display` and `FromStr`. It's displayed as
+//! the names of set flags separated by ` | `, followed by the unknown bits
+//! in hexadecimal, if any. It's parsed from flag names and numbers separated
+//! by `|`. A number is hexadecimal with `0x`, octal with `0o`, binary with
+//! `0b`, or else decimal, so raw values pasted from register dumps are
+//! accepted too. An empty string means no flag is set. So parsing the
+//! display of a value always gets back the same value. The width, fill and
+//! alignment of the format are honored, e.g. `{:<24}`, for columns of tables:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! let f: PrimFlags = "WRITABLE | EXECUTABLE".parse().unwrap();
+//! assert_eq!(f.0, 0b11);
+//! assert_eq!("0x3".parse::<PrimFlags>().unwrap().0, 0b11);
+//! assert_eq!("WRITABLE | 0b100".parse::<PrimFlags>().unwrap().0, 0b101);
+//! assert_eq!("".parse::<PrimFlags>().unwrap().0, 0);
+//! assert!("READABLE".parse::<PrimFlags>().is_err());
+//!
+//! let f = PrimFlags(0b10000010);
+//! assert_eq!(f.to_string(), "EXECUTABLE | 0x80");
+//! assert_eq!(f.to_string().parse::<PrimFlags>().unwrap().0, f.0);
+//! assert_eq!(format!("[{:<12}]", PrimFlags(1)), "[WRITABLE    ]");
+//! assert_eq!(format!("[{:*>12}]", PrimFlags(1)), "[****WRITABLE]");
+//! ```
+//!
+//! A [`ParseError`] tells where the invalid token is, and the valid names,
+//! for config errors. With the `alloc` feature, it also has a copy of the
+//! whole token, for `token()` and in the message:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! let input = "WRITABLE | WRITEABLE_BUT_ONLY_BY_THE_OWNER_OF_THE_FILE";
+//! let err = input.parse::<PrimFlags>().err().unwrap();
+//! assert_eq!(&input[err.span()], "WRITEABLE_BUT_ONLY_BY_THE_OWNER_OF_THE_FILE");
+//! assert_eq!(err.offset(), 11);
+//! # #[cfg(feature = "alloc")] {
+//! assert_eq!(err.token(), "WRITEABLE_BUT_ONLY_BY_THE_OWNER_OF_THE_FILE");
+//! assert_eq!(
+//!     err.to_string(),
+//!     "invalid flag `WRITEABLE_BUT_ONLY_BY_THE_OWNER_OF_THE_FILE` at byte 11, \
+//!      expected a number or one of: WRITABLE, EXECUTABLE",
+//! );
+//! # }
+//! ```
+//!
+//! With the `alloc` feature, the struct also converts to a set of the names
+//! of set flags by `to_name_set()`, and from names by `from_names()`,
+//! which reports the first unknown name by [`UnknownNameError`]:
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     #[derive(Debug)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! let grants = PrimFlags(0b11).to_name_set();
+//! assert!(grants.contains("WRITABLE") && grants.contains("EXECUTABLE"));
+//! assert_eq!(PrimFlags::from_names(&grants).unwrap().0, 0b11);
+//!
+//! let err = PrimFlags::from_names(["WRITABLE", "WRITEABLE"]).unwrap_err();
+//! assert_eq!(err.name(), "WRITEABLE");
+//! # }
+//! ```
+//!
+//! The struct converts to its inner type by `From`, and back by `TryFrom`,
+//! which fails with [`UnknownBitsError`] if any bit is not of a declared flag:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! let f = PrimFlags::try_from(0b11).unwrap();
+//! assert_eq!(u32::from(f), 0b11);
+//! assert!(PrimFlags::try_from(0b100).is_err());
+//! ```
+//!
+//! This, with the names, maps the flags to protobuf messages of prost, as a
+//! `uint32` bitmask or a `repeated` enum, with no glue crate. A test can
+//! check that the enum has a value for each flag:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! # #[derive(Clone, Copy)]
+//! # enum PrimFlag { Unspecified = 0, Writable = 1, Executable = 2 }
+//! # impl PrimFlag {
+//! #     fn as_str_name(&self) -> &'static str {
+//! #         ["PRIM_FLAG_UNSPECIFIED", "PRIM_FLAG_WRITABLE", "PRIM_FLAG_EXECUTABLE"]
+//! #             [*self as usize]
+//! #     }
+//! #     fn from_str_name(name: &str) -> Option<Self> {
+//! #         [Self::Unspecified, Self::Writable, Self::Executable]
+//! #             .into_iter()
+//! #             .find(|v| v.as_str_name() == name)
+//! #     }
+//! # }
+//! // `enum PrimFlag` with `as_str_name()` and `from_str_name()` by prost-build
+//! struct Prim {
+//!     flags: u32,              // uint32 flags = 1;
+//!     flag_list: Vec<PrimFlag>, // repeated PrimFlag flag_list = 2;
+//! }
+//!
+//! let to_enum = |name| PrimFlag::from_str_name(&format!("PRIM_FLAG_{name}"));
+//! let from_enum = |v: &PrimFlag| PrimFlags::from_name(&v.as_str_name()[10..]);
+//!
+//! let f = PrimFlags(PrimFlags::EXECUTABLE);
+//! let msg = Prim {
+//!     flags: f.into(),
+//!     flag_list: f.iter_names().map(|name| to_enum(name).unwrap()).collect(),
+//! };
+//! assert!(PrimFlags::try_from(msg.flags).unwrap().is_executable());
+//! assert!(msg.flag_list.iter().all(|v| from_enum(v).unwrap().is_executable()));
+//!
+//! for name in PrimFlags::FLAG_NAMES {
+//!     assert!(to_enum(name).is_some());
+//! }
+//! ```
+//!
+//! It's also compared with its inner type by `==`, either way round, e.g.
+//! with constants from C headers, or in assertions of tests:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     #[derive(Debug)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//! assert_eq!(PrimFlags(0b11), 0b11);
+//! assert!(PrimFlags::WRITABLE == PrimFlags(0b01));
+//! ```
+//!
+//! All the generated structs implement the [`Flags`] trait, so generic code
+//! can work over any of them.
+//!
+//! For example, `migrate_from()` converts between two layouts of the same
+//! flags by their names, such as two versions of a file format whose bit
+//! positions changed. It fails if a set flag or bit has no counterpart:
+//!
+//! ```rust
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     struct V1: u8 {
+//!         const WRITABLE   = 0b01;
+//!         const EXECUTABLE = 0b10;
+//!     }
+//! }
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     struct V2: u16 {
+//!         const EXECUTABLE = 0b001;
+//!         const WRITABLE   = 0b100;
+//!         const HIDDEN     = 0b010;
+//!     }
+//! }
+//!
+//! assert_eq!(V2::migrate_from(&V1(0b01)).unwrap().0, 0b100);
+//! assert_eq!(V1::migrate_from(&V2(0b101)).unwrap().0, 0b11);
+//! assert!(V1::migrate_from(&V2(0b010)).is_err()); // no HIDDEN in V1
+//! assert!(V2::migrate_from(&V1(0b1000)).is_err()); // unknown bit
+//! ```
+//!
+//! # Options
+//!
+//! Some more code can be generated by options in a `#[tiny_bit_flags(...)]`
+//! attribute on the struct:
 //!
 //! ```diff
 //!  tiny_bit_flags! {
-//! +    #[derive(Copy, Clone, Debug, Default)]
+//! +    #[tiny_bit_flags(serde = "fixed")]
 //!      struct PrimFlags: u32 {
 //! ```
+//!
+//! - `also_as(Name: type, ...)`: generate more structs of the same flags,
+//!   but of other inner types, e.g. `also_as(PrimFlags8: u8)` for the
+//!   storage on disk, so the declarations never diverge. It's a compile
+//!   error if a flag doesn't fit in the type. They convert to the wider
+//!   type by `From`, which keeps the unknown bits too, and to the narrower
+//!   one by `TryFrom`, which fails with [`UnknownBitsError`] if a set bit
+//!   doesn't fit. Both ways are by `TryFrom` if a width is not known by the
+//!   type's name, e.g. of `usize` or an alias. The other options and
+//!   attributes are not given to them.
+//! - `as_ref`: implement `AsRef` and `Borrow` of the inner type, for APIs
+//!   that take the raw integer, e.g. to look up a `HashSet` of the struct
+//!   by the integer. With `no_derives`, `Eq` and `Hash` of the struct must
+//!   agree with the inner type's.
+//! - `async_graphql`: generate a `PrimFlagsFlag` GraphQL enum of the flags,
+//!   and implement `async_graphql::InputType` and `OutputType`, so the
+//!   struct is a list of the enum values in the schema, e.g.
+//!   `[PrimFlagsFlag!]!`, instead of an integer. Resolving a value with
+//!   unknown bits is an error. The `boxed-trait` feature of `async-graphql`
+//!   is not supported. Needs the `async-graphql` feature.
+//! - `atomic`: generate an `AtomicPrimFlags` type, which holds the inner
+//!   value in the atomic integer of its type, for flags shared between
+//!   threads. It has `load()`, `store()`, `swap()`, `fetch_or()`,
+//!   `fetch_and()`, `compare_exchange()`, `compare_exchange_weak()` and
+//!   `fetch_update()` of the struct, and the per-flag methods on `&self`
+//!   with an `Ordering`, plus `set_writable_if_clear()` and
+//!   `clear_writable_if_set()`, which return whether they change the bit.
+//! - `bit_band`: generate `bit_band_writable(register)` and so on, which
+//!   return the bit-band alias address of the flag in the register at the
+//!   address on Cortex-M3/M4, so that a volatile write of 1 or 0 to the
+//!   word sets or clears the flag in a single instruction. They panic if
+//!   the register is out of the bit-band regions of SRAM and peripherals.
+//!   Each flag must be a single bit of an inner type of at most 32 bits.
+//! - `bit_consts`: generate the bit position of each flag, of the lowest
+//!   bit for a multi-bit value, as `WRITABLE_BIT` and so on, e.g. for shift
+//!   amounts of field masks. It's a compile error if a flag is named so,
+//!   e.g. `PARITY` and `PARITY_BIT`.
+//! - `bitfield_struct`: generate `const fn into_bits(self)` and
+//!   `const fn from_bits(bits)`, so the struct can be a field of a
+//!   `bitfield-struct` packed struct. Unlike [`Flags::from_bits`], this
+//!   `from_bits` keeps unknown bits, and it shadows that one for calls
+//!   like `PrimFlags::from_bits(bits)`.
+//! - `bitvec`: implement conversions between the struct and
+//!   `bitvec::array::BitArray<T, Lsb0>`, and generate `as_bitslice()` and
+//!   `as_mut_bitslice()` to view the inner value as a `BitSlice`, where bit
+//!   `n` is the flag of value `1 << n`. The inner type must be unsigned and
+//!   not `u128`. Needs the `bitvec` feature.
+//! - `borsh`: implement `borsh::BorshSerialize` and `borsh::BorshDeserialize`
+//!   with the inner value, so it takes exactly the integer's width. Unknown
+//!   bits are kept. Needs the `borsh` feature.
+//! - `cell`: generate a `CellPrimFlags` type, which holds the inner value in
+//!   a `Cell` and has the per-flag methods on `&self`, for flags shared in a
+//!   single thread, e.g. behind an `Rc`, without `RefCell` borrows.
+//! - `codes = "..."`: give each flag a one-character code, in declaration
+//!   order, as `FLAG_CODES`, e.g. `codes = "wx"`. Then `codes()` displays
+//!   the value in the style of `chmod`, with `-` for unset flags, e.g.
+//!   `-x`, and `from_codes()` parses it back. The codes are ASCII
+//!   characters other than `-`.
+//! - `critical_section`: generate a `PrimFlagsMutex` type, which holds the
+//!   struct in a `critical_section::Mutex`, so that a `static` of it can be
+//!   shared with interrupt handlers on targets without atomics of the width.
+//!   `with(|flags| ...)` accesses it in a critical section, and panics if
+//!   nested. Needs the `critical-section` feature.
+//! - `debug_checks`: check the invariants of the value after each change
+//!   by the per-flag and bit methods, in debug builds only: no bits of
+//!   `reserved` or out of `width` are set. This finds values built with
+//!   such bits, e.g. from a corrupt header, in tests.
+//! - `dense`: check that the flags take the bits from bit 0 up, with no
+//!   unused bit between them, e.g. for formats of densely numbered bits.
+//!   It's a compile error if there is a gap.
+//! - `display_case = "lower"` or `"kebab"`: display the flag names in lower
+//!   case, with `_` replaced by `-` for `"kebab"`, e.g. `write-back` for
+//!   `WRITE_BACK`, to follow the conventions of config files. These names
+//!   are parsed too, besides the declared ones.
+//! - `display_empty = "..."`: display the value without any bit set as the
+//!   given string, e.g. `"(none)"`, instead of the empty string, so that it
+//!   doesn't look like a missing field in logs. It's parsed back too.
+//! - `global`: generate a process-wide value of the struct, in an atomic
+//!   integer, from no bits set, e.g. for runtime feature toggles and kill
+//!   switches. It's accessed by `global_load()`, `global_store()`, and the
+//!   per-flag `global_is_writable()`, `global_set_writable()` and
+//!   `global_clear_writable()`, all with `SeqCst` ordering.
+//! - `impl_attrs(...)`: put the attributes on the generated impl blocks of
+//!   the struct, e.g. `impl_attrs(allow(dead_code))` for unused methods,
+//!   without allowing it for the rest of the crate, or a `cfg` given to the
+//!   struct too. The types and impls by other options get the `cfg` ones
+//!   only, so they go away with the struct.
+//! - `impl_vis(...)`: give the generated constants, methods and field the
+//!   visibility, instead of the struct's, e.g. `impl_vis(pub(crate))` for a
+//!   `pub` struct that other crates can name but not change. The types by
+//!   other options take it too. Flags with their own visibility keep it.
+//! - `inline`, `inline(always)` or `inline(never)`: put the attribute on the
+//!   generated methods of the struct, for control of inlining across
+//!   crates.
+//! - `kani`: generate proof harnesses of `#[cfg(kani)]`, so `cargo kani`
+//!   verifies, for any value, that the per-flag methods set and clear their
+//!   flag only, that the bit methods change bits of declared flags only, and
+//!   never `reserved` ones, and that the known and unknown bits make up the
+//!   value. The harnesses of the per-flag methods are left out unless all
+//!   their families are generated, see `methods(...)`. The harnesses are
+//!   type-checked in other builds too, in a module next to the struct, so
+//!   it must be declared out of a function body. Declare the cfg by
+//!   `check-cfg = ['cfg(kani)']` of the `unexpected_cfgs` lint.
+//! - `map`: generate a `PrimFlagsMap<V>` type, which holds a value of `V`
+//!   for each flag in an array, e.g. counters or timestamps per flag. It's
+//!   indexed by a struct value of exactly one flag, with `get()`,
+//!   `get_mut()`, `set()` and `[]`, and iterated in declaration order.
+//! - `methods(...)`: only generate the given families of per-flag methods,
+//!   out of `is`, `is_in`, `set` and `clear`, e.g. `methods(is)` for
+//!   read-only flags. This keeps the expansion small for types with many
+//!   flags, and avoids the `is_zoom_in()` of `ZOOM` next to the `is_` one
+//!   of a `ZOOM_IN` flag.
+//! - `modular_bitfield`: implement `modular_bitfield::Specifier`, so the
+//!   struct can be a field of a `modular-bitfield` packed struct, taking
+//!   the inner type's width. The inner type must be unsigned. Needs the
+//!   `modular-bitfield` feature.
+//! - `module` or `module = name`: put the flag constants, and the `_BIT`
+//!   ones of `bit_consts`, in a module named after the struct in snake
+//!   case (`prim_flags` for `PrimFlags`) or the given name, instead of in
+//!   the struct. This avoids conflicts between generic flag names and the
+//!   other associated items, and allows to glob-import the constants. They
+//!   keep the same visibility. A value can still refer to another flag by
+//!   `Self::`, but not to the methods of the struct, e.g. `Self::bit(0)`.
+//! - `msb0`: number the bit positions from the highest bit, as in network
+//!   protocol RFCs, for `bit()`, `bit_index()`, the `_BIT` constants,
+//!   which get the highest bit of a multi-bit value then, and the methods
+//!   by bit position.
+//! - `must_use`: put `#[must_use]` on the methods of the struct that only
+//!   return something, such as the `is_` methods, so that a discarded
+//!   check is warned about.
+//! - `no_derives`: don't derive `Clone`, `Copy`, `PartialEq`, `Eq` and `Hash`
+//!   on the struct, e.g. for an inner type without them, or to implement
+//!   them otherwise.
+//! - `non_zero`: generate a `PrimFlagsNonZero` type, which holds the inner
+//!   value as `NonZero`, so an `Option` of it takes no more room than the
+//!   inner type, e.g. in millions of tree nodes. It's made by `new()`,
+//!   which returns `None` for no bits set, and converts back to the struct
+//!   by `get()` or `From`, as does the `Option`, with `None` as no bits set.
+//! - `observed`: generate an `ObservedPrimFlags` wrapper with the per-flag
+//!   methods, which calls a callback with the flag name, and whether it was
+//!   and is set, whenever they change the flag.
+//! - `patch`: generate a `PrimFlagsPatch` type of changes to the struct, with
+//!   the `set` and `clear` bits as public fields, so changes rather than
+//!   whole values can be sent around. It's built by `new()` and the
+//!   per-flag `set_` and `clear_` methods, or by `between(old, new)`, and
+//!   applied by `apply()`, which keeps the other bits.
+//! - `quickcheck`: implement `quickcheck::Arbitrary`, generating subsets of
+//!   the declared flags and shrinking toward the empty set by clearing one
+//!   flag at a time. Needs the `quickcheck` feature, and `Clone` on the
+//!   struct if `no_derives` is given.
+//! - `redis`: implement `redis::ToRedisArgs` and `redis::FromRedisValue`
+//!   as the inner integer, e.g. to cache the flags of a session. Reading a
+//!   value with unknown bits is an error, as of a stale or foreign writer.
+//!   Needs the `redis` feature.
+//! - `registry`: register the struct's name, flag names and values into
+//!   the process-wide registry of the `registry` module, so tools can decode
+//!   its integers knowing only the type name, e.g. of crash dumps. Needs
+//!   the `registry` feature, and a target supported by `inventory`.
+//! - `reserved = mask`: declare the bits of a register or a header that must
+//!   never be written, as `RESERVED_BITS`, by a constant expression. It's
+//!   a compile error if a flag has any of them, so the per-flag and bit
+//!   methods never write them, and they are rejected when parsing. They
+//!   are unknown bits to `TryFrom` and [`Flags::from_bits`], which reject
+//!   them too.
+//! - `rusqlite`: implement `rusqlite::ToSql` and `rusqlite::FromSql` for an
+//!   INTEGER column, of the bits as an `i64`, so a `u64` with the highest
+//!   bit set is stored as a negative number. It's a compile error if the
+//!   inner type is wider than 64 bits, and reading a number out of its
+//!   range is an error. Needs the `rusqlite` feature.
+//! - `rusqlite_strict`: reject unknown bits when reading by `rusqlite`, with
+//!   [`UnknownBitsError`] in `FromSqlError::Other`.
+//! - `scoped`: generate `scoped(bits)`, which sets the bits and returns a
+//!   `PrimFlagsGuard` that restores them to their previous states when
+//!   dropped, even on an early return. The guard dereferences to the struct.
+//! - `serde = "fixed"`: implement `serde::Serialize` and `serde::Deserialize`
+//!   with the backing integer as little-endian bytes, so the value takes
+//!   exactly the integer's width in binary formats like postcard or bincode,
+//!   without varint encoding. Needs the `serde` feature.
+//! - `serde = "comma"`: implement `serde::Serialize` and `serde::Deserialize`
+//!   as a string of the flags as by `Display`, but separated by `,`, e.g.
+//!   `"writable,executable"` with `display_case = "lower"`, for a query
+//!   parameter of `serde_urlencoded`. Each part is parsed as by `FromStr`, and
+//!   the invalid parts are skipped with `serde_lenient`. Needs the `serde`
+//!   feature.
+//! - `serde = "list"`: implement `serde::Serialize` and `serde::Deserialize`
+//!   as a list of the names of the set flags, as written by `Display`, e.g.
+//!   `["WRITABLE","EXECUTABLE"]`, for APIs that document them as an array
+//!   of enum values, see `utoipa`. Unknown bits have no names, so they fail
+//!   to serialize. Unknown names are rejected. Needs the `serde` feature.
+//! - `serde = "map"`: implement `serde::Serialize` and `serde::Deserialize`
+//!   as a map from each flag name to whether it's set, e.g.
+//!   `{"WRITABLE":false,"EXECUTABLE":true}`. Unset flags are included when
+//!   serializing, and unknown bits have no names, so they fail to serialize.
+//!   When deserializing, the flags are the union of the `true` ones, so a
+//!   `false` alias doesn't clear the bits shared with a `true` flag,
+//!   whatever the order. Unknown names are rejected. Needs the `serde`
+//!   feature.
+//! - `serde_flexible`: deserialize from any of an integer, a string as by
+//!   `serde = "comma"`, a list as by `serde = "list"` and a map as by
+//!   `serde = "map"`, whichever of these modes is given to `serde`, so data
+//!   in the historical formats still loads. It needs a self-describing
+//!   format like JSON, and conflicts with `serde = "fixed"`.
+//! - `serde_strict`: reject unknown bits when deserializing, with an error
+//!   naming them, instead of keeping them, so policy files fail closed.
+//!   Unknown flag names are rejected anyway, naming the name, unless
+//!   `serde_lenient`, which conflicts with this option, is given.
+//! - `serde_lenient`: skip unknown flag names when deserializing, instead of
+//!   rejecting them, so config files written by a newer version still load.
+//!   The skipped values are deserialized as `serde::de::IgnoredAny`, so they
+//!   can be collected for a warning with the `serde_ignored` crate.
+//! - `subset(Name: FLAG, ...)`: generate a `Name` type with some of the
+//!   flags only, and its per-flag methods and `bits()`. It converts from the
+//!   struct by `From`, dropping the other bits, so the rest of the flags
+//!   can be kept from less trusted code, and back to it. The option can be
+//!   given more than once.
+//! - `subset_order`: implement `PartialOrd` by inclusion, so `a <= b` if
+//!   all bits of `a` are set in `b`, and neither `a <= b` nor `a >= b` if
+//!   each has bits the other hasn't, e.g. for lattices of permissions. Don't
+//!   derive `PartialOrd` then. With `no_derives`, `PartialEq` must agree.
+//! - `tracked`: generate a `TrackedPrimFlags` wrapper with the per-flag
+//!   methods, which records the bits that they change, so only the changes
+//!   need to be synced. `take_dirty()` returns these bits and starts over.
+//! - `typed_consts`: make the flag constants of the struct's type instead
+//!   of the inner type, e.g. `const WRITABLE: PrimFlags`, so they can be
+//!   passed as flags and used in patterns. A value that refers to another
+//!   flag needs its `.0` then.
+//! - `ufmt`: implement `ufmt::uDisplay` as `Display` but without padding,
+//!   and `ufmt::uDebug` as the same in the struct's name, e.g.
+//!   `PrimFlags(WRITABLE | 0x80)`, for targets where `core::fmt` is too
+//!   heavy. Needs the `ufmt` feature.
+//! - `uniffi`: generate a `PrimFlagsRecord` uniffi record of a `bool` for
+//!   each flag, named in lower case, and the bits of no flag set as a
+//!   whole in `other_bits`, and make the struct a uniffi custom type of it,
+//!   so foreign code gets named fields instead of an integer. The crate
+//!   must depend on `uniffi` and call `uniffi::setup_scaffolding!()`, and
+//!   the inner type must be of at most 64 bits. Needs the `uniffi` feature.
+//! - `utoipa`: implement `utoipa::ToSchema` with the schema of the output
+//!   of the `serde` mode, which is required, so the flags are documented in
+//!   OpenAPI by name instead of as an integer, e.g. as an array of enum
+//!   values with `serde = "list"`. Needs the `utoipa` feature.
+//! - `view`: generate `view()`, which returns a `PrimFlagsView` that borrows
+//!   the struct and has only its `is_` methods and `bits()`, e.g. to hand
+//!   flags to plugins that must not change them.
+//! - `width = bits`: declare the logical width of a field narrower than
+//!   the inner type, e.g. 24 bits next to an 8-bit version in a `u32`, as
+//!   `WIDTH` and `WIDTH_MASK`, by a constant expression. It's a compile
+//!   error if a flag is out of the width, so the per-flag and bit methods
+//!   never write the bits above it, and they are rejected when parsing.
+//!   `pack_into()` and `extract_from()` move the bits of the width only,
+//!   so a field of a 128-bit type fits in their 64-bit word too.
+//!
+//! `as_ref` lets a set of flags be looked up by the inner value:
+//!
+//! ```rust
+//! use std::collections::HashSet;
+//!
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(as_ref)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! let set = HashSet::from([PrimFlags(0b01), PrimFlags(0b11)]);
+//! assert!(set.contains(&0b11));
+//! assert_eq!(*PrimFlags(0b10).as_ref(), 0b10);
+//! ```
+//!
+//! `bitvec` views the bits as a bit slice, and converts to a bit array:
+//!
+//! ```rust
+//! # #[cfg(feature = "bitvec")] {
+//! use bitvec::prelude::*;
+//!
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(bitvec)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! let mut f = PrimFlags(PrimFlags::WRITABLE);
+//! assert_eq!(f.as_bitslice()[..2], bits![1, 0]);
+//! f.as_mut_bitslice().set(1, true);
+//! assert!(f.is_executable());
+//!
+//! let array: BitArray<u32, Lsb0> = f.into();
+//! assert_eq!(array.count_ones(), 2);
+//! # }
+//! ```
+//!
+//! `bitfield_struct` makes the struct a field type of `bitfield-struct`:
+//!
+//! ```rust
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[derive(Debug)]
+//!     #[tiny_bit_flags(bitfield_struct)]
+//!     struct PrimFlags: u8 {
+//!         const WRITABLE   = 0b00000001;
+//!         const EXECUTABLE = 0b00000010;
+//!     }
+//! }
+//!
+//! assert_eq!(PrimFlags::from_bits(0x81).into_bits(), 0x81);
+//! ```
+//!
+//! Then in a packed struct, with `bitfield-struct` in your dependencies:
+//!
+//! ```rust,ignore
+//! #[bitfield_struct::bitfield(u32)]
+//! struct PageEntry {
+//!     #[bits(8)]
+//!     flags: PrimFlags,
+//!     #[bits(24)]
+//!     frame: u32,
+//! }
+//!
+//! let entry = PageEntry::new().with_flags(PrimFlags(PrimFlags::WRITABLE)).with_frame(7);
+//! assert!(entry.flags().is_writable());
+//! assert_eq!(entry.into_bits(), 7 << 8 | 0b01);
+//! ```
+//!
+//! `atomic` shares the flags between threads, with the per-flag methods:
+//!
+//! ```rust
+//! use std::sync::atomic::Ordering;
+//!
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(atomic)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! static STATE: AtomicPrimFlags = AtomicPrimFlags::new(PrimFlags(0));
+//! assert!(STATE.set_writable_if_clear(Ordering::AcqRel));
+//! assert!(!STATE.set_writable_if_clear(Ordering::AcqRel)); // taken already
+//! assert!(STATE.is_writable(Ordering::Acquire));
+//!
+//! // from writable to executable, only if writable
+//! let old = STATE.fetch_update(Ordering::AcqRel, Ordering::Acquire, |f| {
+//!     f.is_writable().then_some(PrimFlags(PrimFlags::EXECUTABLE))
+//! });
+//! assert_eq!(old.ok().map(|f| f.0), Some(PrimFlags::WRITABLE));
+//! let now = STATE.compare_exchange(
+//!     PrimFlags(PrimFlags::WRITABLE),
+//!     PrimFlags(0),
+//!     Ordering::AcqRel,
+//!     Ordering::Acquire,
+//! );
+//! assert_eq!(now.err().map(|f| f.0), Some(PrimFlags::EXECUTABLE));
+//! ```
+//!
+//! `global` keeps one value for the process, e.g. of feature toggles:
+//!
+//! ```rust
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[tiny_bit_flags(global)]
+//!     struct Features: u32 {
+//!         const NEW_PARSER = 0b00000001;
+//!         const FAST_PATH  = 0b00000010;
+//!     }
+//! }
+//!
+//! Features::global_set_new_parser();
+//! assert!(Features::global_is_new_parser());
+//! assert!(!Features::global_is_fast_path());
+//!
+//! Features::global_store(Features(Features::FAST_PATH)); // e.g. from a config
+//! assert_eq!(Features::global_load().0, Features::FAST_PATH);
+//! ```
+//!
+//! `bit_band` gives the bit-band alias of each flag in a register:
+//!
+//! ```rust
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[tiny_bit_flags(bit_band)]
+//!     struct Apb2Enr: u32 {
+//!         const IOPAEN = 1 << 2;
+//!         const IOPBEN = 1 << 3;
+//!     }
+//! }
+//!
+//! const RCC_APB2ENR: usize = 0x4002_1018;
+//! const IOPAEN: usize = Apb2Enr::bit_band_iopaen(RCC_APB2ENR);
+//! assert_eq!(IOPAEN, 0x4242_0308);
+//! assert_eq!(Apb2Enr::bit_band_iopben(0x2000_0004), 0x2200_008c);
+//! // on the target: unsafe { (IOPAEN as *mut u32).write_volatile(1) }
+//! ```
+//!
+//! `subset_order` compares values by inclusion:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(subset_order)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! let (w, x, wx) = (PrimFlags(0b01), PrimFlags(0b10), PrimFlags(0b11));
+//! assert!(w <= wx && w < wx && wx > x);
+//! assert!(!(w <= x) && !(w >= x));
+//! assert!(w <= w && !(w < w));
+//! ```
+//!
+//! `also_as` converts between the same flags in other widths:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(also_as(PrimFlags8: u8, PrimFlags64: u64))]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! let stored = PrimFlags8(PrimFlags8::EXECUTABLE);
+//! let f = PrimFlags::from(stored);
+//! assert!(f.is_executable() && !f.is_writable());
+//! assert_eq!(PrimFlags64::from(f).0, 0b10);
+//! assert_eq!(PrimFlags8::try_from(f).unwrap().0, 0b10);
+//!
+//! // unknown bits are kept, or reported if they don't fit
+//! assert_eq!(PrimFlags::from(PrimFlags8(0x80)).0, 0x80);
+//! assert_eq!(PrimFlags8::try_from(PrimFlags(0b11 | 0x80)).unwrap().0, 0b11 | 0x80);
+//! assert!(PrimFlags8::try_from(PrimFlags(0b11 | 0x100)).is_err());
+//! assert!(PrimFlags::try_from(PrimFlags64(1 << 40)).is_err());
+//! ```
+//!
+//! `also_as` with signed and pointer-sized types:
+//!
+//! ```rust
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[tiny_bit_flags(also_as(PrimFlagsI8: i8, PrimFlagsUsize: usize))]
+//!     struct PrimFlags: u16 {
+//!         const WRITABLE   = 0b00000001;
+//!         const EXECUTABLE = 0b00000010;
+//!     }
+//! }
+//!
+//! // zero-extended, not sign-extended
+//! assert_eq!(PrimFlags::from(PrimFlagsI8(i8::MIN)).0, 0x80);
+//! assert_eq!(PrimFlagsI8::try_from(PrimFlags(0x80)).unwrap().0, i8::MIN);
+//! assert!(PrimFlagsI8::try_from(PrimFlags(0x180)).is_err());
+//!
+//! // the width of `usize` is not known by its name
+//! assert_eq!(PrimFlags::try_from(PrimFlagsUsize(0b10)).unwrap().0, 0b10);
+//! assert_eq!(PrimFlagsUsize::try_from(PrimFlags(0x8000)).unwrap().0, 0x8000);
+//! assert!(PrimFlags::try_from(PrimFlagsUsize(0x10000)).is_err());
+//! ```
+//!
+//! `also_as` to a type too narrow for a flag fails to compile:
+//!
+//! ```compile_fail
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[tiny_bit_flags(also_as(PrimFlags8: u8))]
+//!     struct PrimFlags: u32 {
+//!         const WRITABLE = 1 << 8; // not in `u8`
+//!     }
+//! }
+//! ```
+//!
+//! `kani` generates a proof harness for each flag, and for the masks:
+//!
+//! ```rust
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[tiny_bit_flags(kani, reserved = 0xf0)]
+//!     struct PrimFlags: u8 {
+//!         const WRITABLE   = 0b00000001;
+//!         const EXECUTABLE = 0b00000010;
+//!     }
+//! }
+//!
+//! // `cargo kani` proves `__kani_PrimFlags::writable`, `::executable`,
+//! // `::bits` and `::masks`
+//! # fn main() {}
+//! ```
+//!
+//! `kani` with only the checking methods proves the masks only:
+//!
+//! ```rust
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[tiny_bit_flags(kani, methods(is))]
+//!     struct PrimFlags: u8 {
+//!         const WRITABLE   = 0b00000001;
+//!         const EXECUTABLE = 0b00000010;
+//!     }
+//! }
+//!
+//! // only `__kani_PrimFlags::bits` and `::masks`, as there are no setters
+//! # fn main() {}
+//! ```
+//!
+//! `borsh` writes the inner integer in little endian:
+//!
+//! ```rust
+//! # #[cfg(feature = "borsh")] {
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[tiny_bit_flags(borsh)]
+//!     struct PrimFlags: u16 {
+//!         const WRITABLE   = 0b00000001;
+//!         const EXECUTABLE = 0b00000010;
+//!     }
+//! }
+//!
+//! let bytes = borsh::to_vec(&PrimFlags(PrimFlags::EXECUTABLE)).unwrap();
+//! assert_eq!(bytes, [0b10, 0]);
+//! assert!(borsh::from_slice::<PrimFlags>(&bytes).unwrap().is_executable());
+//! # }
+//! ```
+//!
+//! `uniffi` passes the struct to foreign code as a record of `bool`s:
+//!
+//! ```rust
+//! # #[cfg(feature = "uniffi")]
+//! uniffi::setup_scaffolding!();
+//!
+//! # #[cfg(feature = "uniffi")]
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[tiny_bit_flags(uniffi)]
+//!     pub struct PrimFlags: u32 {
+//!         const WRITABLE   = 0b00000001;
+//!         const EXECUTABLE = 0b00000010;
+//!     }
+//! }
+//!
+//! # #[cfg(feature = "uniffi")]
+//! #[uniffi::export]
+//! fn toggle_writable(mut flags: PrimFlags) -> PrimFlags {
+//!     flags.0 ^= PrimFlags::WRITABLE;
+//!     flags
+//! }
+//!
+//! # fn main() {
+//! # #[cfg(feature = "uniffi")] {
+//! let record = PrimFlagsRecord::from(PrimFlags(0x100 | PrimFlags::EXECUTABLE));
+//! assert!(!record.writable && record.executable);
+//! assert_eq!(record.other_bits, 0x100);
+//! assert_eq!(PrimFlags::from(record).0, 0x100 | PrimFlags::EXECUTABLE);
+//! # }
+//! # }
+//! ```
+//!
+//! `utoipa` describes the output of the `serde` mode:
+//!
+//! ```rust
+//! # #[cfg(all(feature = "serde", feature = "utoipa"))] {
+//! use utoipa::PartialSchema;
+//!
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(serde = "list", utoipa)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! let schema = serde_json::to_value(&PrimFlags::schema()).unwrap();
+//! assert_eq!(
+//!     schema,
+//!     serde_json::json!({
+//!         "type": "array",
+//!         "items": { "type": "string", "enum": ["WRITABLE", "EXECUTABLE"] },
+//!         "uniqueItems": true,
+//!     }),
+//! );
+//! # }
+//! ```
+//!
+//! `async_graphql` takes and returns the struct as a list of enum values:
+//!
+//! ```rust
+//! # #[cfg(feature = "async-graphql")] {
+//! use async_graphql::{EmptyMutation, EmptySubscription, InputType, Object, Schema, Value};
+//!
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(async_graphql)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! struct Query;
+//!
+//! #[Object]
+//! impl Query {
+//!     async fn toggle_writable(&self, flags: PrimFlags) -> PrimFlags {
+//!         PrimFlags(flags.0 ^ PrimFlags::WRITABLE)
+//!     }
+//! }
+//!
+//! let sdl = Schema::new(Query, EmptyMutation, EmptySubscription).sdl();
+//! assert!(sdl.contains("toggleWritable(flags: [PrimFlagsFlag!]!): [PrimFlagsFlag!]!"));
+//! assert!(sdl.contains("enum PrimFlagsFlag {\n\tWRITABLE\n\tEXECUTABLE\n}"));
+//!
+//! let value = PrimFlags(PrimFlags::EXECUTABLE).to_value();
+//! assert_eq!(value.to_string(), "[EXECUTABLE]");
+//! assert_eq!(PrimFlags::parse(Some(value)).ok().unwrap().0, PrimFlags::EXECUTABLE);
+//! assert!(PrimFlags::parse(Some(Value::List(vec![Value::from("DIRTY")]))).is_err());
+//! # }
+//! ```
+//!
+//! `redis` stores the inner integer:
+//!
+//! ```rust
+//! # #[cfg(feature = "redis")] {
+//! use redis::{FromRedisValue, ToRedisArgs, Value};
+//!
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(redis)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! let args = PrimFlags(PrimFlags::WRITABLE | PrimFlags::EXECUTABLE).to_redis_args();
+//! assert_eq!(args, [b"3"]);
+//! let flags = PrimFlags::from_redis_value(Value::Int(2)).unwrap();
+//! assert!(flags.is_executable());
+//! assert!(PrimFlags::from_redis_value(Value::Int(0x82)).is_err());
+//! # }
+//! ```
+//!
+//! `rusqlite` stores all 64 bits in an INTEGER column:
+//!
+//! ```rust
+//! # #[cfg(feature = "rusqlite")] {
+//! use rusqlite::Connection;
+//!
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[tiny_bit_flags(rusqlite, rusqlite_strict)]
+//!     struct PrimFlags: u64 {
+//!         const WRITABLE   = 0b00000001;
+//!         const EXECUTABLE = 1 << 63;
+//!     }
+//! }
+//!
+//! let db = Connection::open_in_memory().unwrap();
+//! db.execute("CREATE TABLE prims (flags INTEGER)", ()).unwrap();
+//! let flags = PrimFlags(PrimFlags::WRITABLE | PrimFlags::EXECUTABLE);
+//! db.execute("INSERT INTO prims VALUES (?1)", [flags]).unwrap();
+//!
+//! let read = |sql| db.query_row(sql, (), |row| row.get::<_, PrimFlags>(0));
+//! assert_eq!(read("SELECT flags FROM prims").unwrap().0, flags.0);
+//! assert_eq!(read("SELECT -9223372036854775807").unwrap().0, flags.0);
+//! assert!(read("SELECT 0b10").is_err());
+//! # }
+//! ```
+//!
+//! `cell` shares the flags in one thread, e.g. in an `Rc`:
+//!
+//! ```rust
+//! use std::rc::Rc;
+//!
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(cell)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! let shared = Rc::new(CellPrimFlags::new(PrimFlags(0)));
+//! let other = Rc::clone(&shared);
+//! other.set_writable();
+//! assert!(shared.is_writable());
+//! assert_eq!(shared.get().0, PrimFlags::WRITABLE);
+//! ```
+//!
+//! `critical_section` shares the flags with interrupts:
+//!
+//! ```rust
+//! # #[cfg(feature = "critical-section")] {
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(critical_section)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! static STATUS: PrimFlagsMutex = PrimFlagsMutex::new(PrimFlags(0));
+//!
+//! STATUS.with(|flags| flags.set_writable());
+//! assert!(STATUS.with(|flags| flags.is_writable()));
+//! # }
+//! ```
+//!
+//! `modular_bitfield` makes the struct a field type of `modular-bitfield`:
+//!
+//! ```rust
+//! # #[cfg(feature = "modular-bitfield")] {
+//! use modular_bitfield::prelude::*;
+//!
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[tiny_bit_flags(modular_bitfield)]
+//!     struct PrimFlags: u8 {
+//!         const WRITABLE   = 0b00000001;
+//!         const EXECUTABLE = 0b00000010;
+//!     }
+//! }
+//!
+//! #[bitfield]
+//! struct PageEntry {
+//!     flags: PrimFlags,
+//!     frame: B24,
+//! }
+//!
+//! let entry = PageEntry::new().with_flags(PrimFlags(PrimFlags::EXECUTABLE)).with_frame(7);
+//! assert!(entry.flags().is_executable());
+//! assert_eq!(entry.into_bytes(), [0b10, 7, 0, 0]);
+//! # }
+//! ```
+//!
+//! `methods(is)` keeps only the checking methods:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(methods(is))]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! let f = PrimFlags(PrimFlags::WRITABLE);
+//! assert!(f.is_writable()); // no set_writable() or clear_writable()
+//! ```
+//!
+//! `module` puts the flag constants in a module to import:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(module, bit_consts)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! use prim_flags::*;
+//! let f = PrimFlags(WRITABLE | EXECUTABLE);
+//! assert!(f.is_writable());
+//! assert_eq!(EXECUTABLE_BIT, 1);
+//! ```
+//!
+//! `module` lets values refer to other flags by `Self::`:
+//!
+//! ```rust
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[tiny_bit_flags(module)]
+//!     struct TcpFlags: u8 {
+//!         const CWR  = 0b01;
+//!         const ECE  = 0b10;
+//!         const BOTH = Self::CWR | Self::ECE;
+//!     }
+//! }
+//!
+//! assert_eq!(tcp_flags::BOTH, 0b11);
+//! assert_eq!(TcpFlags::FLAG_VALUES, [0b01, 0b10, 0b11]);
+//! assert_eq!(TcpFlags(0b11).name(), Some("BOTH"));
+//! ```
+//!
+//! `quickcheck` generates values of the declared flags:
+//!
+//! ```rust
+//! # #[cfg(feature = "quickcheck")] {
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(quickcheck)]
+//! #     #[derive(Debug)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! fn only_declared(f: PrimFlags) -> bool {
+//!     f.0 & !(PrimFlags::WRITABLE | PrimFlags::EXECUTABLE) == 0
+//! }
+//! quickcheck::quickcheck(only_declared as fn(PrimFlags) -> bool);
+//! # }
+//! ```
+//!
+//! `serde = "fixed"` writes the bytes of the inner integer:
+//!
+//! ```rust
+//! # #[cfg(feature = "serde")] {
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(serde = "fixed")]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! let json = serde_json::to_string(&PrimFlags(PrimFlags::EXECUTABLE)).unwrap();
+//! assert_eq!(json, "[2,0,0,0]");
+//!
+//! let f: PrimFlags = serde_json::from_str(&json).unwrap();
+//! assert!(f.is_executable());
+//! # }
+//! ```
+//!
+//! `serde = "map"` writes an object of a `bool` for each flag:
+//!
+//! ```rust
+//! # #[cfg(feature = "serde")] {
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(serde = "map")]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! let json = serde_json::to_string(&PrimFlags(PrimFlags::EXECUTABLE)).unwrap();
+//! assert_eq!(json, r#"{"WRITABLE":false,"EXECUTABLE":true}"#);
+//!
+//! assert_eq!(serde_json::from_str::<PrimFlags>(&json).unwrap().0, PrimFlags::EXECUTABLE);
+//! let f: PrimFlags = serde_json::from_str(r#"{"WRITABLE":true}"#).unwrap();
+//! assert!(f.is_writable() && !f.is_executable());
+//! assert!(serde_json::from_str::<PrimFlags>(r#"{"READABLE":true}"#).is_err());
+//! assert!(serde_json::to_string(&PrimFlags(0x100)).is_err());
+//! # }
+//! ```
+//!
+//! With a multi-bit alias, a `false` entry doesn't clear the bits of a
+//! `true` one, before or after it:
+//!
+//! ```rust
+//! # #[cfg(feature = "serde")] {
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[tiny_bit_flags(serde = "map")]
+//!     struct PrimFlags: u32 {
+//!         const WRITABLE   = 0b00000001;
+//!         const EXECUTABLE = 0b00000010;
+//!         const ALL        = 0b00000011;
+//!     }
+//! }
+//!
+//! for json in [r#"{"WRITABLE":true,"ALL":false}"#, r#"{"ALL":false,"WRITABLE":true}"#] {
+//!     assert_eq!(serde_json::from_str::<PrimFlags>(json).unwrap().0, 0b01);
+//! }
+//! # }
+//! ```
+//!
+//! `serde = "list"` writes an array of the names of the set flags:
+//!
+//! ```rust
+//! # #[cfg(feature = "serde")] {
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(serde = "list")]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! let json = serde_json::to_string(&PrimFlags(0b11)).unwrap();
+//! assert_eq!(json, r#"["WRITABLE","EXECUTABLE"]"#);
+//! assert_eq!(serde_json::from_str::<PrimFlags>(&json).unwrap().0, 0b11);
+//! assert!(serde_json::to_string(&PrimFlags(0x100)).is_err());
+//! assert!(serde_json::from_str::<PrimFlags>(r#"["READABLE"]"#).is_err());
+
+//! # }
+//! ```
+//!
+//! `serde = "comma"` writes one string of the names, here in lower case:
+//!
+//! ```rust
+//! # #[cfg(feature = "serde")] {
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(serde = "comma", display_case = "lower")]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! let json = serde_json::to_string(&PrimFlags(0b11)).unwrap();
+//! assert_eq!(json, r#""writable,executable""#);
+//!
+//! assert_eq!(serde_json::from_str::<PrimFlags>(&json).unwrap().0, 0b11);
+//! let f: PrimFlags = serde_json::from_str(r#""executable, writable""#).unwrap();
+//! assert_eq!(f.0, 0b11);
+//! assert_eq!(serde_json::from_str::<PrimFlags>(r#""""#).unwrap().0, 0);
+//! assert!(serde_json::from_str::<PrimFlags>(r#""readable""#).is_err());
+//! # }
+//! ```
+//!
+//! `serde_lenient` skips unknown names, which `serde_ignored` can collect:
+//!
+//! ```rust
+//! # #[cfg(feature = "serde")] {
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(serde = "map", serde_lenient)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! let json = r#"{"WRITABLE":true,"READABLE":true}"#;
+//! let mut unknown = Vec::new();
+//! let f: PrimFlags = serde_ignored::deserialize(
+//!     &mut serde_json::Deserializer::from_str(json),
+//!     |path| unknown.push(path.to_string()),
+//! ).unwrap();
+//! assert!(f.is_writable());
+//! assert_eq!(unknown, ["READABLE"]);
+//! # }
+//! ```
+//!
+//! `serde_flexible` reads any of the formats, and its own output:
+//!
+//! ```rust
+//! # #[cfg(feature = "serde")] {
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(serde = "map", serde_flexible)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! for json in ["3", r#""WRITABLE | EXECUTABLE""#, r#"["WRITABLE","EXECUTABLE"]"#,
+//!              r#"{"WRITABLE":true,"EXECUTABLE":true}"#] {
+//!     assert_eq!(serde_json::from_str::<PrimFlags>(json).unwrap().0, 0b11);
+//! }
+//! assert!(serde_json::from_str::<PrimFlags>("-1").is_err());
+//!
+//! // reads its own output
+//! let json = serde_json::to_string(&PrimFlags(0b11)).unwrap();
+//! assert_eq!(serde_json::from_str::<PrimFlags>(&json).unwrap().0, 0b11);
+//! # }
+//! ```
+//!
+//! `serde_flexible` also reads an integer into a string mode:
+//!
+//! ```rust
+//! # #[cfg(feature = "serde")] {
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(serde = "comma", serde_flexible)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! let json = serde_json::to_string(&PrimFlags(0b11)).unwrap();
+//! assert_eq!(json, r#""WRITABLE,EXECUTABLE""#);
+//! assert_eq!(serde_json::from_str::<PrimFlags>(&json).unwrap().0, 0b11);
+//! assert_eq!(serde_json::from_str::<PrimFlags>("2").unwrap().0, 0b10);
+//! # }
+//! ```
+//!
+//! `serde_flexible` can't be used with `serde = "fixed"`, whose bytes would
+//! be taken as a list of flags:
+//!
+//! ```rust,compile_fail
+//! # #[cfg(feature = "serde")] {
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[tiny_bit_flags(serde = "fixed", serde_flexible)]
+//!     struct PrimFlags: u32 {
+//!         const WRITABLE   = 0b00000001;
+//!     }
+//! }
+//! # }
+//! # #[cfg(not(feature = "serde"))]
+//! # compile_error!();
+//! ```
+//!
+//! `serde_strict` rejects unknown bits, naming them:
+//!
+//! ```rust
+//! # #[cfg(feature = "serde")] {
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #     #[derive(Debug)]
+//! #[tiny_bit_flags(serde = "map", serde_flexible, serde_strict)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! let err = serde_json::from_str::<PrimFlags>("7").unwrap_err();
+//! assert_eq!(err.to_string(), "unknown bits 0x4 of `PrimFlags` at line 1 column 1");
+//! let err = serde_json::from_str::<PrimFlags>(r#"["WRITABLE","WRITEABLE"]"#).unwrap_err();
+//! assert!(err.to_string().starts_with("unknown variant `WRITEABLE`"));
+//! # }
+//! ```
+//!
+//! `codes` writes and reads one character per flag, as in `ls -l`:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(codes = "wx")]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! assert_eq!(PrimFlags(0b10).codes().to_string(), "-x");
+//! assert_eq!(PrimFlags::from_codes("w-").unwrap().0, 0b01);
+//! assert!(PrimFlags::from_codes("xw").is_err());
+//! ```
+//!
+//! `debug_checks` panics on a change of a corrupt value in debug builds:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(reserved = 0xf000_0000, debug_checks)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! let corrupt = std::panic::catch_unwind(|| PrimFlags(0x1000_0000).set_writable());
+//! assert_eq!(corrupt.is_err(), cfg!(debug_assertions));
+//! ```
+//!
+//! `dense` allows multi-bit values right after the single-bit flags:
+//!
+//! ```rust
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[tiny_bit_flags(dense)]
+//!     struct PrimFlags: u32 {
+//!         const WRITABLE   = 0b00000001;
+//!         const EXECUTABLE = 0b00000010;
+//!         const SHARED     = 0b00001100; // a multi-bit value
+//!     }
+//! }
+//! ```
+//!
+//! `dense` rejects a gap in the bits:
+//!
+//! ```compile_fail
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[tiny_bit_flags(dense)]
+//!     struct PrimFlags: u32 {
+//!         const WRITABLE   = 0b00000001;
+//!         const EXECUTABLE = 0b00000100; // bit 1 is unused
+//!     }
+//! }
+//! ```
+//!
+//! `display_empty` writes and reads a value without bits:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(display_empty = "(none)")]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! assert_eq!(PrimFlags(0).to_string(), "(none)");
+//! assert_eq!("(none)".parse::<PrimFlags>().unwrap().0, 0);
+//! ```
+//!
+//! `impl_attrs` puts attributes on the impls too:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(impl_attrs(allow(dead_code), cfg(target_has_atomic = "32")))]
+//! #     #[cfg(target_has_atomic = "32")]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! # #[cfg(target_has_atomic = "32")]
+//! assert!(PrimFlags(0b01).is_writable());
+//! ```
+//!
+//! and with a `cfg` that is off, nothing is left of the other options:
+//!
+//! ```rust
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[tiny_bit_flags(
+//!         atomic, patch, subset(UserFlags: WRITABLE),
+//!         impl_attrs(cfg(any()))
+//!     )]
+//!     #[cfg(any())]
+//!     struct PrimFlags: u32 {
+//!         const WRITABLE   = 0b00000001;
+//!         const EXECUTABLE = 0b00000010;
+//!     }
+//! }
+//! ```
+//!
+//! `impl_vis` keeps the methods of a public struct private:
+//!
+//! ```rust
+//! mod perms {
+//!     tiny_bit_flags::tiny_bit_flags! {
+//!         #[tiny_bit_flags(impl_vis(pub(self)))]
+//!         pub struct PrimFlags: u32 {
+//!             const WRITABLE   = 0b00000001;
+//!             const EXECUTABLE = 0b00000010;
+//!         }
+//!     }
+//!
+//!     pub fn granted() -> PrimFlags {
+//!         PrimFlags(PrimFlags::WRITABLE)
+//!     }
+//! }
+//!
+//! let f: perms::PrimFlags = perms::granted();
+//! assert_eq!(f.to_string(), "WRITABLE");
+//! ```
+//!
+//! so the methods are not reachable out of the module:
+//!
+//! ```compile_fail
+//! # mod perms {
+//! #     tiny_bit_flags::tiny_bit_flags! {
+//! #         #[tiny_bit_flags(impl_vis(pub(self)))]
+//! #         pub struct PrimFlags: u32 {
+//! #             const WRITABLE   = 0b00000001;
+//! #             const EXECUTABLE = 0b00000010;
+//! #         }
+//! #     }
+//! #     pub fn granted() -> PrimFlags {
+//! #         PrimFlags(PrimFlags::WRITABLE)
+//! #     }
+//! # }
+//! let mut f = perms::granted();
+//! f.set_executable(); // private
+//! ```
+//!
+//! `display_case = "kebab"` writes the names in kebab case, and reads both:
+//!
+//! ```rust
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[tiny_bit_flags(display_case = "kebab")]
+//!     struct CacheFlags: u8 {
+//!         const WRITE_BACK = 0b01;
+//!         const NO_FILL    = 0b10;
+//!     }
+//! }
+//!
+//! assert_eq!(CacheFlags(0b11).to_string(), "write-back | no-fill");
+//! assert_eq!("write-back | NO_FILL".parse::<CacheFlags>().unwrap().0, 0b11);
+//! ```
+//!
+//! `map` keeps a value for each flag:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(map)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! let mut counts = PrimFlagsMap::<u32>::default();
+//! counts[PrimFlags(PrimFlags::WRITABLE)] += 2;
+//! assert_eq!(counts.set(PrimFlags(PrimFlags::EXECUTABLE), 5), Some(0));
+//! assert_eq!(counts.get(PrimFlags(PrimFlags::WRITABLE)), Some(&2));
+//! assert_eq!(counts.get(PrimFlags(0b11)), None); // not one flag
+//! assert_eq!(counts.iter().map(|(_, &n)| n).sum::<u32>(), 7);
+//! ```
+//!
+//! `non_zero` gives a niche to `Option`:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(non_zero)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! assert_eq!(size_of::<Option<PrimFlagsNonZero>>(), size_of::<u32>());
+//! let stored = PrimFlagsNonZero::new(PrimFlags(PrimFlags::WRITABLE));
+//! assert!(PrimFlags::from(stored).is_writable());
+//! assert_eq!(PrimFlagsNonZero::new(PrimFlags(0)), None);
+//! ```
+//!
+//! `msb0` numbers the bits from the highest, as in RFC diagrams:
+//!
+//! ```rust
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[tiny_bit_flags(msb0, bit_consts)]
+//!     struct TcpFlags: u8 {
+//!         const CWR = Self::bit(0);
+//!         const ECE = Self::bit(1);
+//!     }
+//! }
+//!
+//! assert_eq!(TcpFlags::CWR, 0x80);
+//! assert_eq!(TcpFlags::ECE_BIT, 1);
+//! assert_eq!(TcpFlags(0x40).test_bit(1), Some(true));
+//! assert_eq!(TcpFlags(0xc0).iter_bit_indices().collect::<Vec<_>>(), [0, 1]);
+//! ```
+//!
+//! A bit position out of the type fails to compile:
+//!
+//! ```rust,compile_fail
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[tiny_bit_flags(msb0)]
+//!     struct TcpFlags: u8 {
+//!         const CWR = Self::bit(8);
+//!     }
+//! }
+//! ```
+//!
+//! `observed` calls back on each change:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(observed)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! let mut changes = Vec::new();
+//! let mut f = ObservedPrimFlags::new(PrimFlags(0), |name, old, new| {
+//!     changes.push((name, old, new));
+//! });
+//! f.set_writable();
+//! f.set_writable(); // no change
+//! f.clear_writable();
+//! drop(f);
+//! assert_eq!(changes, [("WRITABLE", false, true), ("WRITABLE", true, false)]);
+//! ```
+//!
+//! `patch` applies and computes changes of the declared flags only:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(patch)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! let patch = PrimFlagsPatch::new().set_writable().clear_executable();
+//! let mut f = PrimFlags(0x100 | PrimFlags::EXECUTABLE);
+//! patch.apply(&mut f);
+//! assert_eq!(f.0, 0x100 | PrimFlags::WRITABLE);
+//! let between = PrimFlagsPatch::between(&PrimFlags(0x100), &f);
+//! assert_eq!(between, PrimFlagsPatch::new().set_writable());
+//! ```
+//!
+//! `reserved` rejects the reserved bits in parsing and conversions:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(reserved = 0xf000_0000)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! assert_eq!(PrimFlags::RESERVED_BITS, 0xf000_0000);
+//! assert!("WRITABLE | 0x10000000".parse::<PrimFlags>().is_err());
+//! assert!(PrimFlags::try_from(0x1000_0001).is_err());
+//! ```
+//!
+//! `reserved` and `width` take constant expressions:
+//!
+//! ```rust
+//! const VERSION_BITS: u32 = 8;
+//!
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(reserved = 1 << 23, width = 32 - VERSION_BITS)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! assert_eq!(PrimFlags::RESERVED_BITS, 0x0080_0000);
+//! assert_eq!(PrimFlags::WIDTH, 24);
+//! ```
+//!
+//! `scoped` restores the bits at the end of the scope:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(scoped)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! let mut f = PrimFlags(0);
+//! {
+//!     let mut guard = f.scoped(PrimFlags::WRITABLE);
+//!     assert!(guard.is_writable());
+//!     guard.set_executable(); // not restored
+//! }
+//! assert!(!f.is_writable());
+//! assert!(f.is_executable());
+//! ```
+//!
+//! `subset` gives a narrower type of some of the flags:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(subset(UserFlags: WRITABLE))]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! let mut user = UserFlags::from(PrimFlags(0b11));
+//! assert!(user.is_writable()); // no `is_executable()` here
+//! assert_eq!(user.bits(), 0b01);
+//! user.clear_writable();
+//! assert_eq!(PrimFlags::from(user).0, 0);
+//! ```
+//!
+//! `tracked` records the changed bits:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(tracked)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! let mut f = TrackedPrimFlags::new(PrimFlags(PrimFlags::WRITABLE));
+//! f.set_writable(); // no change
+//! f.set_executable();
+//! assert!(f.is_executable());
+//! assert_eq!(f.take_dirty().0, PrimFlags::EXECUTABLE);
+//! assert_eq!(f.take_dirty().0, 0);
+//! ```
+//!
+//! `ufmt` writes as `Display` and `Debug` without `core::fmt`:
+//!
+//! ```rust
+//! # #[cfg(feature = "ufmt")] {
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[tiny_bit_flags(ufmt)]
+//!     struct PrimFlags: u8 {
+//!         const WRITABLE   = 0b00000001;
+//!         const EXECUTABLE = 0b00000010;
+//!     }
+//! }
+//!
+//! let mut s = String::new();
+//! ufmt::uwrite!(s, "{} {:?}", PrimFlags(0b11), PrimFlags(0x81)).unwrap();
+//! assert_eq!(s, "WRITABLE | EXECUTABLE PrimFlags(WRITABLE | 0x80)");
+//! # }
+//! ```
+//!
+//! `view` lends the flags for reading only:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(view)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! fn plugin(flags: PrimFlagsView) -> bool {
+//!     flags.is_writable() // no `set_writable()` here
+//! }
+//!
+//! let f = PrimFlags(PrimFlags::WRITABLE);
+//! assert!(plugin(f.view()));
+//! ```
+//!
+//! `typed_consts` makes the constants usable as flags and in patterns:
+//!
+//! ```rust
+//! use tiny_bit_flags::Flags;
+//!
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(typed_consts)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! let f = PrimFlags(0b11);
+//! assert!(f.contains(PrimFlags::WRITABLE));
+//! match PrimFlags(0b10) {
+//!     PrimFlags::EXECUTABLE => {}
+//!     _ => unreachable!(),
+//! }
+//! ```
+//!
+//! `width` packs only the bits of the width, next to other fields:
+//!
+//! ```rust
+//! # tiny_bit_flags::tiny_bit_flags! {
+//! #[tiny_bit_flags(width = 24)]
+//! #     struct PrimFlags: u32 {
+//! #         const WRITABLE   = 0b00000001;
+//! #         const EXECUTABLE = 0b00000010;
+//! #     }
+//! # }
+//!
+//! assert_eq!(PrimFlags::WIDTH_MASK, 0x00ff_ffff);
+//! let header = PrimFlags(0b11).pack_into(0x0700_0000, 0).unwrap(); // version 7 kept
+//! assert_eq!(header, 0x0700_0003);
+//! assert_eq!(PrimFlags::extract_from(header, 0).unwrap().0, 0b11);
+//! assert_eq!(PrimFlags(0b11).pack_into(0, 40), Some(0b11 << 40)); // 24 bits fit
+//! assert!("0x1000000".parse::<PrimFlags>().is_err());
+//! ```
+//!
+//! `width` lets a 128-bit type fit in the 64-bit word of `pack_into()`:
+//!
+//! ```rust
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     struct WideFlags: u128 {
+//!         const HIGH = 1 << 100;
+//!     }
+//! }
+//!
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[tiny_bit_flags(width = 8)]
+//!     struct NarrowFlags: u128 {
+//!         const LOW = 1;
+//!     }
+//! }
+//!
+//! assert_eq!(WideFlags(0).pack_into(0, 0), None); // wider than the word
+//! assert_eq!(NarrowFlags(1).pack_into(0, 56), Some(1 << 56));
+//! ```
+//!
+//! For flags defined outside Rust, e.g. in a schema shared with other
+//! languages, the `build` module of the `build` feature generates the
+//! invocations from a spec file in a build script.
+//!
+//! For tools that decode flags of types they don't know at compile time,
+//! the `registry` module of the `registry` feature lists the structs
+//! generated with the `registry` option, by name.
+
+#[cfg(feature = "build")]
+pub mod build;
+
+#[cfg(feature = "registry")]
+pub mod registry;
+
+/// Generate bit-flags struct and methods.
+///
+/// See module-level document for details.
+///
+/// Example:
+///
+/// ```rust
+/// tiny_bit_flags::tiny_bit_flags! {
+///     struct PrimFlags: u32 {
+///         const WRITABLE   = 0b00000001;
+///         const EXECUTABLE = 0b00000010;
+///     }
+/// }
+///
+/// let mut f = PrimFlags(PrimFlags::WRITABLE); // initialize
+/// assert!(f.is_writable()); // check flag
+/// assert!(!f.is_executable());
+///
+/// f.clear_writable(); // clear flag
+/// assert!(!f.is_writable());
+///
+/// f.set_executable(); // set flag
+/// assert!(f.is_executable());
+/// ```
+///
+/// The generated items don't conflict with the per-flag ones of any flag
+/// name. Only the per-flag functions on raw bits do, for a flag named
+/// after another with `_IN`, so they are left out by `methods(...)` here:
+///
+/// ```rust
+/// tiny_bit_flags::tiny_bit_flags! {
+///     #[tiny_bit_flags(methods(is, set, clear))]
+///     struct ViewFlags: u8 {
+///         const ZOOM       = 0b0001;
+///         const ZOOM_IN    = 0b0010;
+///         const PARITY     = 0b0100;
+///         const PARITY_BIT = 0b1000;
+///         const BIT        = 0b1_0000;
+///         const NAMES      = 0b10_0000;
+///     }
+/// }
+///
+/// assert!(ViewFlags(0b10).is_zoom_in() && !ViewFlags(0b10).is_zoom());
+/// assert!(ViewFlags(ViewFlags::PARITY_BIT).is_parity_bit());
+/// let mut f = ViewFlags(0);
+/// f.set_bit();
+/// assert!(f.is_bit() && f.insert_bit(5) && f.is_names());
+/// f.clear_names();
+/// assert!(f.remove_bit(4) && f.test_bit(4) == Some(false));
+/// assert_eq!(ViewFlags(0b11_0000).iter_names().collect::<Vec<_>>(), ["BIT", "NAMES"]);
+/// ```
+///
+/// Malformed declarations are reported by flag name, e.g.
+/// "missing `;` or `,` after the value of flag `WRITABLE`":
+///
+/// ```rust,compile_fail
+/// tiny_bit_flags::tiny_bit_flags! {
+///     struct PrimFlags: u32 {
+///         const WRITABLE   = 0b00000001
+///         const EXECUTABLE = 0b00000010;
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! tiny_bit_flags {
+    // the inner type of a plain name is kept as the `__inner` option too,
+    // by which `also_as` tells the widths of the types
+    (
+        $(#[$($outer:tt)*])*
+        $vis:vis struct $BitFlags:ident: $T:ident { $($body:tt)* }
+    ) => {
+        $crate::__tiny_bit_flags! {
+            @entry
+            #[tiny_bit_flags(__inner = $T)]
+            $(#[$($outer)*])*
+            $vis struct $BitFlags: $T { $($body)* }
+        }
+    };
+    ($($input:tt)*) => {
+        $crate::__tiny_bit_flags! { @entry $($input)* }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags {
+    // the declarations given to `tiny_bit_flags!`
+    (
+        @entry
+        $(#[$($outer:tt)*])*
+        $vis:vis struct $BitFlags:ident: $T:ty {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                $(pub $(($($fvis:tt)*))?)? const $Flag:tt = $value:expr;
+            )*
+        }
+    ) => {
+        $crate::__tiny_bit_flags! {
+            @none [] [$(#[$($outer)*])* $vis struct $BitFlags: $T] []
+            $(
+                $(#[$inner $($args)*])*
+                $(pub $(($($fvis)*))?)? const $Flag = $value;
+            )*
+        }
+    };
+
+    // diagnostics of common mistakes
+    (
+        @entry
+        $(#[$($outer:tt)*])*
+        $vis:vis struct $BitFlags:ident { $($body:tt)* }
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "missing inner type of `", ::core::stringify!($BitFlags), "`, ",
+            "e.g. `struct ", ::core::stringify!($BitFlags), ": u32 { ... }`"
+        ));
+    };
+
+    // relaxed syntax: `,` terminators and the form without `const`
+    (
+        @entry
+        $(#[$($outer:tt)*])*
+        $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }
+    ) => {
+        $crate::__tiny_bit_flags! {
+            @normalize [$(#[$($outer)*])* $vis struct $BitFlags: $T] []
+            $($body)*
+        }
+    };
+
+    // take `NONE` out of the flags, as the name of the empty value, into
+    // the `__none` option
+    (@none [$($opt:tt)*] [$($head:tt)*] [$($acc:tt)*]) => {
+        $crate::__tiny_bit_flags! { @parse [$($opt)*] [] [] $($head)* { $($acc)* } }
+    };
+    (
+        @none [$($opt:tt)*] $head:tt $acc:tt
+        $(#[$($attr:tt)*])* $(pub $(($($fvis:tt)*))?)? const NONE = $value:expr; $($rest:tt)*
+    ) => {
+        $crate::__tiny_bit_flags! {
+            @none [$($opt)* __none = {[$(#[$($attr)*])*] [$(pub $(($($fvis)*))?)?] $value},] $head $acc
+            $($rest)*
+        }
+    };
+    (
+        @none $opts:tt $head:tt [$($acc:tt)*]
+        $(#[$($attr:tt)*])* $(pub $(($($fvis:tt)*))?)? const $Flag:tt = $value:expr; $($rest:tt)*
+    ) => {
+        $crate::__tiny_bit_flags! {
+            @none $opts $head [$($acc)* $(#[$($attr)*])* $(pub $(($($fvis)*))?)? const $Flag = $value;]
+            $($rest)*
+        }
+    };
+
+    // rewrite flag declarations into `const NAME = value;`, and `NONE` as
+    // by `@none`
+    (@normalize [$($head:tt)*] [$($acc:tt)*]) => {
+        $crate::__tiny_bit_flags! { @parse [] [] [] $($head)* { $($acc)* } }
+    };
+    (
+        @normalize [$($head:tt)*] $acc:tt $(#[$($attr:tt)*])* $(pub $(($($fvis:tt)*))?)?
+        $(const)? NONE = $value:expr $(; $($rest:tt)*)?
+    ) => {
+        $crate::__tiny_bit_flags! {
+            @normalize [
+                #[tiny_bit_flags(__none = {[$(#[$($attr)*])*] [$(pub $(($($fvis)*))?)?] $value})]
+                $($head)*
+            ] $acc $($($rest)*)?
+        }
+    };
+    (
+        @normalize [$($head:tt)*] $acc:tt $(#[$($attr:tt)*])* $(pub $(($($fvis:tt)*))?)?
+        $(const)? NONE = $value:expr, $($rest:tt)*
+    ) => {
+        $crate::__tiny_bit_flags! {
+            @normalize [
+                #[tiny_bit_flags(__none = {[$(#[$($attr)*])*] [$(pub $(($($fvis)*))?)?] $value})]
+                $($head)*
+            ] $acc $($rest)*
+        }
+    };
+    (
+        @normalize $head:tt $acc:tt $(#[$($attr:tt)*])* $(pub $(($($fvis:tt)*))?)?
+        const $Flag:tt = $($rest:tt)*
+    ) => {
+        $crate::__tiny_bit_flags! {
+            @normalize_value $head $acc
+            [$(#[$($attr)*])* $(pub $(($($fvis)*))?)? const $Flag =]
+            $($rest)*
+        }
+    };
+    (
+        @normalize $head:tt $acc:tt $(#[$($attr:tt)*])* $(pub $(($($fvis:tt)*))?)?
+        const $($rest:tt)*
+    ) => {
+        $crate::__tiny_bit_flags! { @check $(#[$($attr)*])* $(pub $(($($fvis)*))?)? const $($rest)* }
+    };
+    (
+        @normalize $head:tt $acc:tt $(#[$($attr:tt)*])* pub $(($($fvis:tt)*))?
+        $Flag:ident = $($rest:tt)*
+    ) => {
+        $crate::__tiny_bit_flags! {
+            @normalize_value $head $acc
+            [$(#[$($attr)*])* pub $(($($fvis)*))? const $Flag =]
+            $($rest)*
+        }
+    };
+    (@normalize $head:tt $acc:tt $(#[$($attr:tt)*])* $Flag:ident = $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! {
+            @normalize_value $head $acc [$(#[$($attr)*])* const $Flag =] $($rest)*
+        }
+    };
+    (@normalize $head:tt $acc:tt $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @check $($rest)* }
+    };
+    (@normalize_value $head:tt [$($acc:tt)*] [$($decl:tt)*] $value:expr $(; $($rest:tt)*)?) => {
+        $crate::__tiny_bit_flags! { @normalize $head [$($acc)* $($decl)* $value;] $($($rest)*)? }
+    };
+    (@normalize_value $head:tt [$($acc:tt)*] [$($decl:tt)*] $value:expr, $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @normalize $head [$($acc)* $($decl)* $value;] $($rest)* }
+    };
+    (@normalize_value $head:tt $acc:tt [$($decl:tt)*] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @check $($decl)* $($rest)* }
+    };
+
+    // find the first invalid flag declaration
+    (@check) => {
+        ::core::compile_error!("invalid flags declaration");
+    };
+    (
+        @check $(#[$($attr:tt)*])* $(pub $(($($fvis:tt)*))?)?
+        const $Flag:ident = $value:expr; $($rest:tt)*
+    ) => {
+        $crate::__tiny_bit_flags! { @check $($rest)* }
+    };
+    (
+        @check $(#[$($attr:tt)*])* $(pub $(($($fvis:tt)*))?)?
+        const $Flag:ident = $($rest:tt)*
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "missing `;` or `,` after the value of flag `",
+            ::core::stringify!($Flag),
+            "`",
+        ));
+    };
+    (
+        @check $(#[$($attr:tt)*])* $(pub $(($($fvis:tt)*))?)?
+        const $Flag:ident $($rest:tt)*
+    ) => {
+        ::core::compile_error!(::core::concat!("missing `= value` after flag `", ::core::stringify!($Flag), "`"));
+    };
+    (@check $(#[$($attr:tt)*])* $(pub $(($($fvis:tt)*))?)? const $($rest:tt)*) => {
+        ::core::compile_error!("missing flag name after `const`");
+    };
+    (@check $($rest:tt)*) => {
+        ::core::compile_error!("expected flag declaration, e.g. `const WRITABLE = 0b00000001;`");
+    };
+
+    // split `#[tiny_bit_flags(...)]` options and the derived traits from the
+    // other outer attributes
+    (@parse $opts:tt $outer:tt $derive:tt #[tiny_bit_flags($($o:tt)*)] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @parse_options $opts [$($o)*] $outer $derive $($rest)* }
+    };
+    (@parse $opts:tt $outer:tt [$($derive:tt)*] #[derive($($d:tt)*)] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @parse $opts $outer [$($derive)* $($d)*,] $($rest)* }
+    };
+    (@parse $opts:tt [$($outer:tt)*] $derive:tt #[$attr:meta] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @parse $opts [$($outer)* #[$attr]] $derive $($rest)* }
+    };
+    (@parse [$($opt:tt)*] $outer:tt $derive:tt $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        $crate::__tiny_bit_flags! { @impl_vis [$($opt)*] [$($opt)*] $outer $derive $vis struct $BitFlags: $T { $($body)* } }
+    };
+
+    // the options of one attribute, as `key`, `key(...)` or `key = value`
+    // of one token tree, in which an expression value, e.g. of `reserved =
+    // 1 << 31`, is parenthesized
+    (@parse_options $opts:tt [$(,)?] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @parse $opts $($rest)* }
+    };
+    (@parse_options [$($opt:tt)*] [$key:ident $(($($args:tt)*))? $(, $($o:tt)*)?] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @parse_options [$($opt)* $key $(($($args)*))?,] [$($($o)*)?] $($rest)* }
+    };
+    (@parse_options [$($opt:tt)*] [$key:ident = $value:tt $(, $($o:tt)*)?] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @parse_options [$($opt)* $key = $value,] [$($($o)*)?] $($rest)* }
+    };
+    (@parse_options [$($opt:tt)*] [$key:ident = $value:expr $(, $($o:tt)*)?] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @parse_options [$($opt)* $key = ($value),] [$($($o)*)?] $($rest)* }
+    };
+    (@parse_options $opts:tt [$($o:tt)*] $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!("invalid options: ", ::core::stringify!($($o)*)));
+    };
+
+    // the visibility by `impl_vis(...)` for the items but the struct, whose
+    // own one is kept by `__struct_vis`
+    (@impl_vis [] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @items $($rest)* }
+    };
+    (
+        @impl_vis [impl_vis($ivis:vis) $($_:tt)*] [$($opt:tt)*] $outer:tt $derive:tt
+        $vis:vis struct $($rest:tt)*
+    ) => {
+        $crate::__tiny_bit_flags! { @items [$($opt)* __struct_vis($vis),] $outer $derive $ivis struct $($rest)* }
+    };
+    (@impl_vis [$_:tt $($more:tt)*] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @impl_vis [$($more)*] $($rest)* }
+    };
+
+    (@items [$($opt:tt)*] $outer:tt $derive:tt $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        $crate::__tiny_bit_flags! { @if no_derives [$($opt)*] {
+            $crate::__tiny_bit_flags! {
+                @derives [$($opt)*] $outer [no_derives] $derive $vis struct $BitFlags: $T { $($body)* }
+            }
+        } {
+            $crate::__tiny_bit_flags! {
+                @derives [$($opt)*] $outer [] $derive $vis struct $BitFlags: $T { $($body)* }
+            }
+        } }
+        $crate::__tiny_bit_flags! {
+            @cfg_of [$($opt)*] [] @options [$($opt)*] [$($opt)*] $vis struct $BitFlags: $T { $($body)* }
+        }
+        $crate::__tiny_bit_flags! { @cfg_of [$($opt)*] [] @each_flag ($) $BitFlags: $T { $($body)* } }
+    };
+
+    // the `cfg(...)` attributes of `impl_attrs(...)`, on the invocation of
+    // `@$then` too, so the items by other options go with the impls
+    (@cfg_of [] [$($cfg:tt)*] @$then:ident $($rest:tt)*) => {
+        $($cfg)*
+        $crate::__tiny_bit_flags! { @$then $($rest)* }
+    };
+    (@cfg_of [impl_attrs($($a:tt)*) $($more:tt)*] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @cfg_in [$($a)*] [$($more)*] $($rest)* }
+    };
+    (@cfg_of [$_:tt $($more:tt)*] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @cfg_of [$($more)*] $($rest)* }
+    };
+    (@cfg_in [cfg $args:tt $($a:tt)*] $more:tt [$($cfg:tt)*] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @cfg_in [$($a)*] $more [$($cfg)* #[cfg $args]] $($rest)* }
+    };
+    (@cfg_in [$_:tt $($a:tt)*] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @cfg_in [$($a)*] $($rest)* }
+    };
+    (@cfg_in [] $more:tt $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @cfg_of $more $($rest)* }
+    };
+
+    // the callback macro of `for_each_flag!`, over the flags in declaration
+    // order, with `$d` for the `$` of its own metavariables
+    (
+        @each_flag ($d:tt) $BitFlags:ident: $T:ty {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                $(pub $(($($fvis:tt)*))?)? const $Flag:tt = $value:expr;
+            )*
+        }
+    ) => {
+        $crate::__paste! {
+            #[doc(hidden)]
+            #[allow(unused_macros)]
+            macro_rules! [<__tiny_bit_flags_each_ $BitFlags>] {
+                ($d callback:ident) => {
+                    $($d callback! { $BitFlags, $Flag, $crate::__tiny_bit_flags!(@value $BitFlags $Flag) })*
+                };
+            }
+        }
+    };
+
+    // the derived traits, in addition to `Clone`, `Copy`, `PartialEq`, `Eq`
+    // and `Hash`, which are derived anyway unless `no_derives` is given
+    (@derives $opts:tt [$($outer:tt)*] [no_derives] [$($derive:tt)*] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! {
+            @fn_attrs $opts [] [] @impl_attrs $opts [] @impl $opts [$($outer)* #[derive($($derive)*)]] $($rest)*
+        }
+    };
+    (@derives $opts:tt [$($outer:tt)*] [$($keep:tt)*] [] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! {
+            @fn_attrs $opts [] [] @impl_attrs $opts [] @impl $opts [
+                #[derive(
+                    ::core::clone::Clone, ::core::marker::Copy,
+                    ::core::cmp::PartialEq, ::core::cmp::Eq, ::core::hash::Hash,
+                    $($keep)*
+                )]
+                $($outer)*
+            ]
+            $($rest)*
+        }
+    };
+    (@derives $opts:tt $outer:tt $keep:tt [, $($more:tt)*] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @derives $opts $outer $keep [$($more)*] $($rest)* }
+    };
+    (@derives $opts:tt $outer:tt $keep:tt [Clone $(, $($more:tt)*)?] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @derives $opts $outer $keep [$($($more)*)?] $($rest)* }
+    };
+    (@derives $opts:tt $outer:tt $keep:tt [Copy $(, $($more:tt)*)?] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @derives $opts $outer $keep [$($($more)*)?] $($rest)* }
+    };
+    (@derives $opts:tt $outer:tt $keep:tt [PartialEq $(, $($more:tt)*)?] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @derives $opts $outer $keep [$($($more)*)?] $($rest)* }
+    };
+    (@derives $opts:tt $outer:tt $keep:tt [Eq $(, $($more:tt)*)?] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @derives $opts $outer $keep [$($($more)*)?] $($rest)* }
+    };
+    (@derives $opts:tt $outer:tt $keep:tt [Hash $(, $($more:tt)*)?] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @derives $opts $outer $keep [$($($more)*)?] $($rest)* }
+    };
+    (@derives $opts:tt $outer:tt [$($keep:tt)*] [$derive:path $(, $($more:tt)*)?] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @derives $opts $outer [$($keep)* $derive,] [$($($more)*)?] $($rest)* }
+    };
+
+    // the struct and its methods
+    (
+        @impl [$(#[$inline:meta])*] [$(#[$must_use:meta])*] [$(#[$impl_attr:meta])*] $opts:tt [$(#[$outer:meta])*]
+        $vis:vis struct $BitFlags:ident: $T:ty {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                $(pub $(($($fvis:tt)*))?)? const $Flag:tt = $value:expr;
+            )*
+        }
+    ) => {
+        // struct
+        $crate::__tiny_bit_flags! { @struct $opts [$(#[$outer])*] [$vis] $BitFlags: $T }
+
+        // no flag is zero, whose methods would never work
+        $(#[$impl_attr])*
+        const _: () = {
+            $(
+                ::core::assert!(
+                    $crate::__tiny_bit_flags!(@value $BitFlags $Flag) != 0,
+                    ::core::concat!("flag `", ::core::stringify!($Flag), "` of `", ::core::stringify!($BitFlags), "` is zero"),
+                );
+            )*
+        };
+
+        $(#[$impl_attr])*
+        impl $BitFlags {
+            $(
+                $crate::__tiny_bit_flags! {
+                    @flag $opts [$(pub $(($($fvis)*))?)?] [$vis]
+                    $(#[$inner $($args)*])*
+                    const $Flag: $T = $value;
+                }
+            )*
+
+            // names of flags, in declaration order
+            $vis const FLAG_NAMES: &'static [&'static str] = &[$(::core::stringify!($Flag)),*];
+
+            // values of flags, in declaration order
+            $vis const FLAG_VALUES: &'static [$T] = &[$($value),*];
+
+            // doc comments of flags, in declaration order
+            $vis const FLAG_DESCRIPTIONS: &'static [&'static str] = &[$(
+                $crate::__tiny_bit_flags!(@doc [] $(#[$inner $($args)*])*).trim_ascii()
+            ),*];
+
+            // number of flags
+            $vis const NUM_FLAGS: usize = Self::FLAG_NAMES.len();
+
+            // the longest output of `Display` without padding, e.g. to size
+            // a buffer for `write_names()`: all names, and the unknown bits
+            $vis const MAX_DISPLAY_LEN: usize = {
+                let mut len = "0x".len() + <$T>::BITS as usize / 4;
+                let mut i = 0;
+                while i < Self::NUM_FLAGS {
+                    len += Self::FLAG_NAMES[i].len() + " | ".len();
+                    i += 1;
+                }
+                let empty = $crate::__tiny_bit_flags!(@get display_empty $opts { "" }).len();
+                if empty > len { empty } else { len }
+            };
+
+            // name of the flag, if the value is exactly one flag
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis const fn name(&self) -> ::core::option::Option<&'static str> {
+                $(
+                    if self.0 == $value {
+                        return ::core::option::Option::Some(::core::stringify!($Flag));
+                    }
+                )*
+                ::core::option::Option::None
+            }
+
+            // doc comment of the flag, if the value is exactly one flag
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis const fn description(&self) -> ::core::option::Option<&'static str> {
+                let mut i = 0;
+                while i < Self::NUM_FLAGS {
+                    if self.0 == Self::FLAG_VALUES[i] {
+                        return ::core::option::Option::Some(Self::FLAG_DESCRIPTIONS[i]);
+                    }
+                    i += 1;
+                }
+                ::core::option::Option::None
+            }
+
+            // hash table of names, built at compile time, for from_name()
+            const __NAME_TABLE: [usize; $crate::__name_table_len(Self::NUM_FLAGS)] =
+                $crate::__name_table(Self::FLAG_NAMES);
+
+            // the flag of the name
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis const fn from_name(name: &str) -> ::core::option::Option<Self> {
+                match $crate::__name_lookup(&Self::__NAME_TABLE, Self::FLAG_NAMES, name) {
+                    ::core::option::Option::Some(i) => ::core::option::Option::Some(Self(Self::FLAG_VALUES[i])),
+                    ::core::option::Option::None => ::core::option::Option::None,
+                }
+            }
+
+            // position of the bit, if the value is exactly one bit
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis const fn bit_index(&self) -> ::core::option::Option<u32> {
+                if self.0.count_ones() == 1 {
+                    ::core::option::Option::Some($crate::__tiny_bit_flags!(@if msb0 $opts {
+                        self.0.leading_zeros()
+                    } {
+                        self.0.trailing_zeros()
+                    }))
+                } else {
+                    ::core::option::Option::None
+                }
+            }
+
+            // the bit at the position, e.g. for values of flags; panics, or
+            // fails to compile in a const, if `n` is out of the type
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis const fn bit(n: u32) -> $T {
+                ::core::assert!(n < <$T>::BITS, "bit position out of the type");
+                (1 as $T) << $crate::__tiny_bit_flags!(@if msb0 $opts { (<$T>::BITS - 1 - n) } { n })
+            }
+
+            // access by bit position, only to bits of declared flags
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis const fn test_bit(&self, n: u32) -> ::core::option::Option<bool> {
+                match Self::__declared_bit(n) {
+                    ::core::option::Option::Some(bit) => ::core::option::Option::Some(self.0 & bit != 0),
+                    ::core::option::Option::None => ::core::option::Option::None,
+                }
+            }
+            $(#[$inline])*
+            $vis const fn insert_bit(&mut self, n: u32) -> bool {
+                match Self::__declared_bit(n) {
+                    ::core::option::Option::Some(bit) => {
+                        self.0 |= bit;
+                        $crate::__tiny_bit_flags! { @debug_checks $opts self }
+                        true
+                    }
+                    ::core::option::Option::None => false,
+                }
+            }
+            $(#[$inline])*
+            $vis const fn remove_bit(&mut self, n: u32) -> bool {
+                match Self::__declared_bit(n) {
+                    ::core::option::Option::Some(bit) => {
+                        self.0 &= !bit;
+                        $crate::__tiny_bit_flags! { @debug_checks $opts self }
+                        true
+                    }
+                    ::core::option::Option::None => false,
+                }
+            }
+            $(#[$inline])*
+            const fn __declared_bit(n: u32) -> ::core::option::Option<$T> {
+                let n = $crate::__tiny_bit_flags!(@if msb0 $opts {
+                    match (<$T>::BITS - 1).checked_sub(n) {
+                        ::core::option::Option::Some(n) => n,
+                        ::core::option::Option::None => return ::core::option::Option::None,
+                    }
+                } {
+                    n
+                });
+                match (1 as $T).checked_shl(n) {
+                    ::core::option::Option::Some(bit) if bit & (0 $(| $value)*) != 0 => ::core::option::Option::Some(bit),
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            // exchange the states of the flags, e.g. of left and right when
+            // mirroring; each is set if all of its bits are
+            $(#[$inline])*
+            $vis const fn swap_flags(&mut self, a: Self, b: Self) {
+                let (a_set, b_set) = (self.0 & a.0 == a.0, self.0 & b.0 == b.0);
+                self.0 &= !(a.0 | b.0);
+                if a_set {
+                    self.0 |= b.0;
+                }
+                if b_set {
+                    self.0 |= a.0;
+                }
+            }
+
+            // overwrite the bits of `mask` with those of `from`, e.g. to merge
+            // some flags of a remote update; the other bits are kept
+            $(#[$inline])*
+            $vis const fn copy_masked(&mut self, from: Self, mask: Self) {
+                self.0 = (self.0 & !mask.0) | (from.0 & mask.0);
+                $crate::__tiny_bit_flags! { @debug_checks $opts self }
+            }
+
+            // whether all bits of `include` are set and none of `exclude`, e.g.
+            // for queries of entities by their components
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis const fn matches(self, include: Self, exclude: Self) -> bool {
+                self.0 & include.0 == include.0 && self.0 & exclude.0 == 0
+            }
+
+            // the bits of `required` that are not set, if any, e.g. for an
+            // error of permission naming them by `Display`
+            $(#[$inline])*
+            $vis const fn require(&self, required: Self) -> ::core::result::Result<(), Self> {
+                match required.0 & !self.0 {
+                    0 => ::core::result::Result::Ok(()),
+                    missing => ::core::result::Result::Err(Self(missing)),
+                }
+            }
+
+            // the bits of declared flags, and the other bits, e.g. for the
+            // unknown bits to be forwarded untouched by protocol bridges
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis const fn split_known(self) -> (Self, $T) {
+                let known = 0 $(| $value)*;
+                (Self(self.0 & known), self.0 & !known)
+            }
+
+            // equality without branches on the bits, e.g. for capability masks
+            // in authentication; `black_box` keeps the compiler from adding
+            // the early exit back, as far as it can
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis fn ct_eq(&self, other: &Self) -> bool {
+                let diff = ::core::hint::black_box(self.0 ^ other.0);
+                // the highest bit of `diff | -diff` is set unless `diff` is 0
+                ((diff | diff.wrapping_neg()) >> (<$T>::BITS - 1)) & 1 == 0
+            }
+
+            // whether each flag is set, in declaration order
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis const fn to_bools(&self) -> [bool; Self::NUM_FLAGS] {
+                let mut bools = [false; Self::NUM_FLAGS];
+                let mut i = 0;
+                while i < Self::NUM_FLAGS {
+                    bools[i] = self.0 & Self::FLAG_VALUES[i] == Self::FLAG_VALUES[i];
+                    i += 1;
+                }
+                bools
+            }
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis const fn from_bools(bools: [bool; Self::NUM_FLAGS]) -> Self {
+                let mut bits = 0;
+                let mut i = 0;
+                while i < Self::NUM_FLAGS {
+                    if bools[i] {
+                        bits |= Self::FLAG_VALUES[i];
+                    }
+                    i += 1;
+                }
+                Self(bits)
+            }
+
+            // union of all the values, e.g. the flags of several roles
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis const fn union_all(flags: &[Self]) -> Self {
+                let mut bits = 0;
+                let mut i = 0;
+                while i < flags.len() {
+                    bits |= flags[i].0;
+                    i += 1;
+                }
+                Self(bits)
+            }
+
+            // number of the values with each flag set, in declaration order,
+            // and with all bits of the flag set, as by the `is_` methods,
+            // e.g. for histograms of records
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis const fn count_each(flags: &[Self]) -> [usize; Self::NUM_FLAGS] {
+                let mut counts = [0; Self::NUM_FLAGS];
+                let mut i = 0;
+                while i < flags.len() {
+                    let mut j = 0;
+                    while j < Self::NUM_FLAGS {
+                        counts[j] += (flags[i].0 & Self::FLAG_VALUES[j] == Self::FLAG_VALUES[j]) as usize;
+                        j += 1;
+                    }
+                    i += 1;
+                }
+                counts
+            }
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis const fn count_with(flags: &[Self], flag: Self) -> usize {
+                let mut count = 0;
+                let mut i = 0;
+                while i < flags.len() {
+                    count += (flags[i].0 & flag.0 == flag.0) as usize;
+                    i += 1;
+                }
+                count
+            }
+
+            // whether any or all of the values, or which of them, have all
+            // bits of the mask set; without branches within chunks of values,
+            // so that the compiler can vectorize them for large slices
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis fn any_set_in(flags: &[Self], mask: Self) -> bool {
+                use ::core::iter::Iterator as _;
+                let mut chunks = flags.chunks_exact(16);
+                for chunk in &mut chunks {
+                    if chunk.iter().fold(false, |any, flag| any | (flag.0 & mask.0 == mask.0)) {
+                        return true;
+                    }
+                }
+                chunks.remainder().iter().any(|flag| flag.0 & mask.0 == mask.0)
+            }
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis fn all_set_in(flags: &[Self], mask: Self) -> bool {
+                use ::core::iter::Iterator as _;
+                let mut chunks = flags.chunks_exact(16);
+                for chunk in &mut chunks {
+                    if !chunk.iter().fold(true, |all, flag| all & (flag.0 & mask.0 == mask.0)) {
+                        return false;
+                    }
+                }
+                chunks.remainder().iter().all(|flag| flag.0 & mask.0 == mask.0)
+            }
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis fn filter_indices(flags: &[Self], mask: Self) -> impl ::core::iter::Iterator<Item = usize> + '_ {
+                use ::core::iter::Iterator as _;
+                flags.chunks(16).enumerate().flat_map(move |(n, chunk)| {
+                    let mut hits = 0u16;
+                    for (i, flag) in chunk.iter().enumerate() {
+                        hits |= ((flag.0 & mask.0 == mask.0) as u16) << i;
+                    }
+                    (0..chunk.len()).filter(move |i| hits & (1 << i) != 0).map(move |i| n * 16 + i)
+                })
+            }
+
+            // embed the bits in a larger word at the bit offset, or get them out,
+            // or `None` if the word has no room for the whole inner type, or
+            // the logical width by `width`, e.g. always of a 128-bit type
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis const fn pack_into(self, word: u64, offset: u32) -> ::core::option::Option<u64> {
+                let width: u32 = $crate::__tiny_bit_flags!(@get width $opts { <$T>::BITS });
+                match offset.checked_add(width) {
+                    ::core::option::Option::Some(end) if end <= 64 => {
+                        let mask = (u64::MAX >> (64 - width)) << offset;
+                        ::core::option::Option::Some((word & !mask) | (((self.0 as u64) << offset) & mask))
+                    }
+                    _ => ::core::option::Option::None,
+                }
+            }
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis const fn extract_from(word: u64, offset: u32) -> ::core::option::Option<Self> {
+                let width: u32 = $crate::__tiny_bit_flags!(@get width $opts { <$T>::BITS });
+                match offset.checked_add(width) {
+                    ::core::option::Option::Some(end) if end <= 64 => {
+                        let mask = u64::MAX >> (64 - width);
+                        ::core::option::Option::Some(Self(((word >> offset) & mask) as $T))
+                    }
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            // the change from `old` to `new`, as the names of the set flags with
+            // `+` and the cleared ones with `-`, and then the changed unknown
+            // bits in hexadecimal, separated by spaces, e.g. for audit logs
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis fn format_changes(old: Self, new: Self) -> impl ::core::fmt::Display {
+                struct Changes($T, $T);
+
+                impl ::core::fmt::Display for Changes {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        use ::core::iter::Iterator as _;
+                        let case = $crate::__tiny_bit_flags!(@get display_case $opts { "" });
+                        let (set, cleared) = (self.1 & !self.0, self.0 & !self.1);
+                        let (mut rest_set, mut rest_cleared) = (set, cleared);
+                        let mut sep = "";
+                        for (&name, &value) in $BitFlags::FLAG_NAMES.iter().zip($BitFlags::FLAG_VALUES) {
+                            for (sign, changed, rest) in [("+", set, &mut rest_set), ("-", cleared, &mut rest_cleared)] {
+                                // not if written by an earlier flag, as by `Display`
+                                if changed & value == value && *rest & value != 0 {
+                                    f.write_str(sep)?;
+                                    f.write_str(sign)?;
+                                    $crate::__write_name(f, name, case)?;
+                                    *rest &= !value;
+                                    sep = " ";
+                                }
+                            }
+                        }
+                        for (sign, rest) in [("+", rest_set), ("-", rest_cleared)] {
+                            if rest != 0 {
+                                ::core::write!(f, "{}{}{:#x}", sep, sign, rest)?;
+                                sep = " ";
+                            }
+                        }
+                        ::core::result::Result::Ok(())
+                    }
+                }
+
+                Changes(old.0, new.0)
+            }
+
+            // access by a pointer that may be unaligned, e.g. to a field of a
+            // `#[repr(packed)]` struct, which can't be borrowed; `ptr` must be
+            // valid for reads, or writes, as by `ptr::read_unaligned()` and
+            // `ptr::write_unaligned()`
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis const unsafe fn read_packed(ptr: *const Self) -> Self {
+                unsafe { ptr.read_unaligned() }
+            }
+            $(#[$inline])*
+            $vis const unsafe fn write_packed(ptr: *mut Self, flags: Self) {
+                unsafe { ptr.write_unaligned(flags) }
+            }
+            $(#[$inline])*
+            $vis unsafe fn update_packed<R>(ptr: *mut Self, f: impl ::core::ops::FnOnce(&mut Self) -> R) -> R {
+                let mut flags = unsafe { ptr.read_unaligned() };
+                let result = f(&mut flags);
+                unsafe { ptr.write_unaligned(flags) };
+                result
+            }
+
+            // the flags of the same names in another layout, e.g. an older
+            // version of a file format, or an error if any set flag or bit
+            // of it has no flag here
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis fn migrate_from<F: $crate::Flags>(other: &F) -> ::core::result::Result<Self, $crate::UnknownBitsError> {
+                use ::core::iter::Iterator as _;
+                if F::from_bits(other.bits()).is_none() {
+                    return ::core::result::Result::Err($crate::UnknownBitsError(()));
+                }
+                let mut bits = 0;
+                for (name, &value) in F::FLAG_NAMES.iter().zip(F::FLAG_VALUES) {
+                    if let ::core::option::Option::Some(flag) = F::from_bits(value) {
+                        if other.contains(flag) {
+                            match Self::from_name(name) {
+                                ::core::option::Option::Some(this) => bits |= this.0,
+                                ::core::option::Option::None => return ::core::result::Result::Err($crate::UnknownBitsError(())),
+                            }
+                        }
+                    }
+                }
+                ::core::result::Result::Ok(Self(bits))
+            }
+
+            // the flags set to `true`, by names as in `FromStr`, e.g. of toggles
+            // in a config map, or an error with the index of the first pair
+            // of an unknown name
+            $(#[$inline])*
+            $vis fn from_pairs<I, S>(pairs: I) -> ::core::result::Result<Self, $crate::PairError>
+            where
+                I: ::core::iter::IntoIterator<Item = (S, bool)>,
+                S: ::core::convert::AsRef<str>,
+            {
+                use ::core::iter::Iterator as _;
+                let case = $crate::__tiny_bit_flags!(@get display_case $opts { "" });
+                let mut bits = 0;
+                for (index, (name, set)) in pairs.into_iter().enumerate() {
+                    let name = ::core::convert::AsRef::<str>::as_ref(&name);
+                    let flag = Self::from_name(name).or_else(|| {
+                        Self::FLAG_NAMES
+                            .iter()
+                            .position(|n| $crate::__name_matches(n, name, case))
+                            .map(|i| Self(Self::FLAG_VALUES[i]))
+                    });
+                    match flag {
+                        ::core::option::Option::Some(flag) if set => bits |= flag.0,
+                        ::core::option::Option::Some(_) => {}
+                        ::core::option::Option::None => return ::core::result::Result::Err($crate::PairError::__at(index, name, Self::FLAG_NAMES)),
+                    }
+                }
+                ::core::result::Result::Ok(Self(bits))
+            }
+
+            // changes by `+` or `-` and a name or number as in `FromStr`,
+            // separated by `,`, e.g. `+WRITABLE,-EXECUTABLE` to tweak the
+            // defaults; the later wins, and nothing changes on an error
+            $(#[$inline])*
+            $vis fn apply_str(&mut self, s: &str) -> ::core::result::Result<(), $crate::ParseError> {
+                if s.trim().is_empty() {
+                    return ::core::result::Result::Ok(());
+                }
+                let (mut set, mut clear) = (0, 0);
+                for (offset, token) in $crate::__split_trimmed(s, ',') {
+                    let err = |offset, len| $crate::ParseError::__at(s, offset, len, Self::FLAG_NAMES);
+                    let (add, rest) = match token.as_bytes().first() {
+                        ::core::option::Option::Some(b'+') => (true, &token[1..]),
+                        ::core::option::Option::Some(b'-') => (false, &token[1..]),
+                        _ => return ::core::result::Result::Err(err(offset, token.len())),
+                    };
+                    // the span of the name, empty for a missing one
+                    let offset = offset + 1 + (rest.len() - rest.trim_start().len());
+                    let name = rest.trim();
+                    if name.is_empty() {
+                        return ::core::result::Result::Err(err(offset, 0));
+                    }
+                    let flags = name
+                        .parse::<Self>()
+                        .map_err(|e| err(offset + e.offset(), e.span().end - e.offset()))?;
+                    if add {
+                        set |= flags.0;
+                        clear &= !flags.0;
+                    } else {
+                        clear |= flags.0;
+                        set &= !flags.0;
+                    }
+                }
+                self.0 = (self.0 & !clear) | set;
+                ::core::result::Result::Ok(())
+            }
+
+            // iterator over all flags, in declaration order
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis fn all_flags() -> impl ::core::iter::Iterator<Item = Self> {
+                use ::core::iter::Iterator as _;
+                Self::FLAG_VALUES.iter().map(|&value| Self(value))
+            }
+
+            // iterator over all combinations of flags, from no flags to all,
+            // e.g. for exhaustive tests; up to 20 flags, or about a million,
+            // and `None` for more
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis fn power_set() -> ::core::option::Option<impl ::core::iter::Iterator<Item = Self>> {
+                use ::core::iter::Iterator as _;
+                if Self::NUM_FLAGS > 20 {
+                    return ::core::option::Option::None;
+                }
+                ::core::option::Option::Some((0..1u32 << Self::NUM_FLAGS).map(|set| {
+                    let mut bits = 0;
+                    for (i, &value) in Self::FLAG_VALUES.iter().enumerate() {
+                        if set & (1 << i) != 0 {
+                            bits |= value;
+                        }
+                    }
+                    Self(bits)
+                }))
+            }
+
+            // iterator over names of the set flags, in declaration order
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis fn iter_names(&self) -> impl ::core::iter::Iterator<Item = &'static str> + use<> {
+                use ::core::iter::Iterator as _;
+                let bits = self.0;
+                Self::FLAG_NAMES
+                    .iter()
+                    .zip(Self::FLAG_VALUES)
+                    .filter(move |&(_, &value)| bits & value == value)
+                    .map(|(&name, _)| name)
+            }
+
+            // iterator over positions of the set bits of declared flags, in
+            // ascending order, e.g. as column indices of bitmaps
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis fn iter_bit_indices(&self) -> impl ::core::iter::Iterator<Item = u32> + use<> {
+                use ::core::iter::Iterator as _;
+                let mut bits = self.0 & Self::FLAG_VALUES.iter().fold(0, |all, &value| all | value);
+                ::core::iter::from_fn(move || {
+                    if bits == 0 {
+                        return ::core::option::Option::None;
+                    }
+                    let n = $crate::__tiny_bit_flags!(@if msb0 $opts {
+                        bits.leading_zeros()
+                    } {
+                        bits.trailing_zeros()
+                    });
+                    bits &= !Self::bit(n);
+                    ::core::option::Option::Some(n)
+                })
+            }
+        }
+
+        $(#[$impl_attr])*
+        impl ::core::fmt::Display for $BitFlags {
+            // names of the set flags, and then the unknown bits in hexadecimal,
+            // separated by ` | `, so that `FromStr` gets back the same value;
+            // padded to the width if given, e.g. for columns of tables
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                $crate::__write_padded(f, |f| self.write_names(f))
+            }
+        }
+
+        $(#[$impl_attr])*
+        impl $BitFlags {
+            // the output of `Display` without padding, e.g. into a buffer of
+            // `MAX_DISPLAY_LEN` on the stack
+            $vis fn write_names(&self, mut f: &mut (impl ::core::fmt::Write + ?::core::marker::Sized)) -> ::core::fmt::Result {
+                if self.0 == 0 {
+                    return f.write_str($crate::__tiny_bit_flags!(@get display_empty $opts { "" }));
+                }
+                self.__write_separated(&mut f, " | ")
+            }
+
+            // whether each flag is a single bit, higher than the previous
+            // one's, so the set bits in ascending order are the set flags
+            // in declaration order
+            const __SINGLE_BITS: bool = {
+                let mut i = 0;
+                while i < Self::NUM_FLAGS {
+                    let value = Self::FLAG_VALUES[i];
+                    if value.count_ones() != 1 || (i > 0 && value.trailing_zeros() <= Self::FLAG_VALUES[i - 1].trailing_zeros()) {
+                        break;
+                    }
+                    i += 1;
+                }
+                i == Self::NUM_FLAGS
+            };
+
+            // index of the flag of each bit position, by `__SINGLE_BITS`
+            const __BIT_FLAGS: [u8; <$T>::BITS as usize] = {
+                let mut table = [0; <$T>::BITS as usize];
+                let mut i = 0;
+                while Self::__SINGLE_BITS && i < Self::NUM_FLAGS {
+                    table[Self::FLAG_VALUES[i].trailing_zeros() as usize] = i as u8;
+                    i += 1;
+                }
+                table
+            };
+
+            // names of the set flags, and then the unknown bits, by `Display`
+            // and by `serde = "comma"`
+            fn __write_separated(&self, f: &mut dyn ::core::fmt::Write, separator: &str) -> ::core::fmt::Result {
+                use ::core::iter::Iterator as _;
+                let case = $crate::__tiny_bit_flags!(@get display_case $opts { "" });
+                let mut rest = self.0;
+                let mut sep = "";
+                if Self::__SINGLE_BITS {
+                    // only as many rounds as set flags, e.g. for hot logs
+                    let mut bits = self.0 & (0 $(| $value)*);
+                    while bits != 0 {
+                        let n = bits.trailing_zeros();
+                        f.write_str(sep)?;
+                        $crate::__write_name(f, Self::FLAG_NAMES[Self::__BIT_FLAGS[n as usize] as usize], case)?;
+                        bits &= !((1 as $T) << n);
+                        sep = separator;
+                    }
+                    rest &= !(0 $(| $value)*);
+                } else {
+                    for (&name, &value) in Self::FLAG_NAMES.iter().zip(Self::FLAG_VALUES) {
+                        if rest == 0 {
+                            break;
+                        }
+                        if value != 0 && self.0 & value == value && rest & value != 0 {
+                            f.write_str(sep)?;
+                            $crate::__write_name(f, name, case)?;
+                            rest &= !value;
+                            sep = separator;
+                        }
+                    }
+                }
+                if rest != 0 {
+                    f.write_str(sep)?;
+                    ::core::write!(f, "{:#x}", rest)?;
+                }
+                ::core::result::Result::Ok(())
+            }
+        }
+
+        $(#[$impl_attr])*
+        impl ::core::str::FromStr for $BitFlags {
+            type Err = $crate::ParseError;
+
+            // names or numbers, separated by `|`
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                use ::core::iter::Iterator as _;
+                let mut bits = 0;
+                let empty = $crate::__tiny_bit_flags!(@get display_empty $opts { "" });
+                let case = $crate::__tiny_bit_flags!(@get display_case $opts { "" });
+                if s.trim().is_empty() || s.trim() == empty {
+                    return ::core::result::Result::Ok(Self(bits));
+                }
+                for (offset, token) in $crate::__split_trimmed(s, '|') {
+                    let err = || $crate::ParseError::__at(s, offset, token.len(), Self::FLAG_NAMES);
+                    let flag = Self::from_name(token).or_else(|| {
+                        Self::FLAG_NAMES
+                            .iter()
+                            .position(|name| $crate::__name_matches(name, token, case))
+                            .map(|i| Self(Self::FLAG_VALUES[i]))
+                    });
+                    let value = match flag {
+                        ::core::option::Option::Some(flag) => flag.0,
+                        ::core::option::Option::None => match $crate::__parse_raw_bits(token, <$T>::BITS) {
+                            ::core::option::Option::Some(::core::option::Option::Some(raw)) => raw as $T,
+                            ::core::option::Option::Some(::core::option::Option::None) => return ::core::result::Result::Err(err()),
+                            ::core::option::Option::None => token.parse::<$T>().map_err(|_| err())?,
+                        },
+                    };
+                    // no bits of `reserved` or out of `width`
+                    if value & $crate::__tiny_bit_flags!(@get reserved $opts { 0 }) != 0 {
+                        return ::core::result::Result::Err(err());
+                    }
+                    $crate::__tiny_bit_flags! { @if width $opts {
+                        if value & !Self::WIDTH_MASK != 0 {
+                            return ::core::result::Result::Err(err());
+                        }
+                    } {} }
+                    bits |= value;
+                }
+                ::core::result::Result::Ok(Self(bits))
+            }
+        }
+
+        $(#[$impl_attr])*
+        impl ::core::convert::From<$BitFlags> for $T {
+            fn from(flags: $BitFlags) -> $T {
+                flags.0
+            }
+        }
+
+        $(#[$impl_attr])*
+        impl ::core::cmp::PartialEq<$T> for $BitFlags {
+            fn eq(&self, other: &$T) -> bool {
+                self.0 == *other
+            }
+        }
+
+        $(#[$impl_attr])*
+        impl ::core::cmp::PartialEq<$BitFlags> for $T {
+            fn eq(&self, other: &$BitFlags) -> bool {
+                *self == other.0
+            }
+        }
+
+        $(#[$impl_attr])*
+        impl ::core::convert::TryFrom<$T> for $BitFlags {
+            type Error = $crate::UnknownBitsError;
+
+            // only the bits of declared flags
+            fn try_from(bits: $T) -> ::core::result::Result<Self, Self::Error> {
+                if bits & !(0 $(| $value)*) == 0 {
+                    ::core::result::Result::Ok(Self(bits))
+                } else {
+                    ::core::result::Result::Err($crate::UnknownBitsError(()))
+                }
+            }
+        }
+
+        $(#[$impl_attr])*
+        impl $crate::Flags for $BitFlags {
+            type Bits = $T;
+
+            const FLAG_NAMES: &'static [&'static str] = Self::FLAG_NAMES;
+            const FLAG_VALUES: &'static [$T] = Self::FLAG_VALUES;
+
+            fn empty() -> Self {
+                Self(0)
+            }
+            fn all() -> Self {
+                Self(0 $(| $value)*)
+            }
+            fn bits(&self) -> $T {
+                self.0
+            }
+            fn from_bits(bits: $T) -> ::core::option::Option<Self> {
+                if bits & !(0 $(| $value)*) == 0 {
+                    ::core::option::Option::Some(Self(bits))
+                } else {
+                    ::core::option::Option::None
+                }
+            }
+            fn contains(&self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+        }
+
+        $crate::__tiny_bit_flags_alloc! {
+            [$(#[$inline])*] [$(#[$must_use])*] [$(#[$impl_attr])*] $opts $vis struct $BitFlags: $T
+        }
+    };
+
+    // the struct, of its own visibility if kept by `impl_vis(...)`, but with
+    // the field of the methods' one
+    (@struct [] [$(#[$outer:meta])*] [$vis:vis] $BitFlags:ident: $T:ty) => {
+        $(#[$outer])*
+        $vis struct $BitFlags($vis $T);
+    };
+    (@struct [__struct_vis($svis:vis) $($_:tt)*] [$(#[$outer:meta])*] [$vis:vis] $BitFlags:ident: $T:ty) => {
+        $(#[$outer])*
+        $svis struct $BitFlags($vis $T);
+    };
+    (@struct [$_:tt $($more:tt)*] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @struct [$($more)*] $($rest)* }
+    };
+
+    // constants and methods of one flag, with its own visibility if given
+    (@flag $opts:tt [] [$vis:vis] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @fn_attrs $opts [] [] @flag_items $opts [$vis] $($rest)* }
+    };
+    (@flag $opts:tt [$($fvis:tt)+] [$vis:vis] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @fn_attrs $opts [] [] @flag_items $opts [$($fvis)+] $($rest)* }
+    };
+    (
+        @flag_items [$(#[$inline:meta])*] [$(#[$must_use:meta])*] $opts:tt [$vis:vis]
+        $(#[$inner:meta])*
+        const $Flag:tt: $T:ty = $value:expr;
+    ) => {
+        // constant values, unless in the module by `module`
+        $crate::__tiny_bit_flags! { @if module $opts {} {
+            $crate::__tiny_bit_flags! { @if typed_consts $opts {
+                $(#[$inner])*
+                $vis const $Flag: Self = Self($value);
+            } {
+                $(#[$inner])*
+                $vis const $Flag: $T = $value;
+            } }
+            $crate::__tiny_bit_flags! { @if bit_consts $opts {
+                $crate::__paste! {
+                    $vis const [<$Flag _BIT>]: u32 = {
+                        let value: $T = $value;
+                        $crate::__tiny_bit_flags!(@if msb0 $opts {
+                            value.leading_zeros()
+                        } {
+                            value.trailing_zeros()
+                        })
+                    };
+                }
+            } {} }
+        } }
+
+        // methods
+        $crate::__tiny_bit_flags! { @methods is $opts
+            $crate::__paste! {
+                $(#[$inline])*
+                $(#[$must_use])*
+                $vis const fn [<is_ $Flag:lower>](&self) -> bool {
+                    self.0 & $value == $value
+                }
+            }
+        }
+        $crate::__tiny_bit_flags! { @methods is_in $opts
+            $crate::__paste! {
+                // the same on raw bits, e.g. of records in a mapped file
+                $(#[$inline])*
+                $(#[$must_use])*
+                $vis const fn [<is_ $Flag:lower _in>](bits: $T) -> bool {
+                    bits & $value == $value
+                }
+            }
+        }
+        $crate::__tiny_bit_flags! { @methods set $opts
+            $crate::__paste! {
+                $(#[$inline])*
+                $vis const fn [<set_ $Flag:lower>](&mut self) {
+                    self.0 |= $value;
+                    $crate::__tiny_bit_flags! { @debug_checks $opts self }
+                }
+            }
+        }
+        $crate::__tiny_bit_flags! { @methods clear $opts
+            $crate::__paste! {
+                $(#[$inline])*
+                $vis const fn [<clear_ $Flag:lower>](&mut self) {
+                    self.0 &= !$value;
+                    $crate::__tiny_bit_flags! { @debug_checks $opts self }
+                }
+            }
+        }
+    };
+
+    // options, one by one, each with all options
+    (@options [] $($info:tt)*) => {};
+    (@options [, $($rest:tt)*] $($info:tt)*) => {
+        $crate::__tiny_bit_flags! { @options [$($rest)*] $($info)* }
+    };
+    (@options [$key:ident $(= $value:tt)? $(, $($rest:tt)*)?] $($info:tt)*) => {
+        $crate::__tiny_bit_flags! { @option $key $(= $value)?; $($info)* }
+        $crate::__tiny_bit_flags! { @options [$($($rest)*)?] $($info)* }
+    };
+    (@options [$key:ident ($($args:tt)*) $(, $($rest:tt)*)?] $($info:tt)*) => {
+        $crate::__tiny_bit_flags! { @option $key($($args)*); $($info)* }
+        $crate::__tiny_bit_flags! { @options [$($($rest)*)?] $($info)* }
+    };
+
+    // option: `serde = "..."`
+    (@option serde = $mode:tt; $($info:tt)*) => {
+        $crate::__tiny_bit_flags_serde! { $mode; $($info)* }
+    };
+    // option: `serde_lenient`, used by `serde`
+    (@option serde_lenient; $($info:tt)*) => {};
+    // option: `serde_flexible`, used by `serde`
+    (@option serde_flexible; $($info:tt)*) => {};
+    // option: `serde_strict`, used by `serde`
+    (@option serde_strict; $opts:tt $($info:tt)*) => {
+        $crate::__tiny_bit_flags! { @if serde_lenient $opts {
+            ::core::compile_error!("`serde_strict` conflicts with `serde_lenient`");
+        } {} }
+    };
+    // option: `typed_consts`, used by `@impl` and `@module`
+    (@option typed_consts; $($info:tt)*) => {};
+    // option: `bitvec`
+    (@option bitvec; $($info:tt)*) => {
+        $crate::__tiny_bit_flags_bitvec! { $($info)* }
+    };
+    // option: `subset_order`
+    (@option subset_order; [$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        impl ::core::cmp::PartialOrd for $BitFlags {
+            // `a <= b` if `a` is a subset of `b`, or `None` if neither is
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                let common = self.0 & other.0;
+                if self.0 == other.0 {
+                    ::core::option::Option::Some(::core::cmp::Ordering::Equal)
+                } else if common == self.0 {
+                    ::core::option::Option::Some(::core::cmp::Ordering::Less)
+                } else if common == other.0 {
+                    ::core::option::Option::Some(::core::cmp::Ordering::Greater)
+                } else {
+                    ::core::option::Option::None
+                }
+            }
+        }
+    };
+    // option: `also_as(Name: type, ...)`
+    (
+        @option also_as($($args:tt)*); $opts:tt
+        $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }
+    ) => {
+        $crate::__tiny_bit_flags! { @also_as_inner $opts [$($args)*] { $($body)* } [$vis] $BitFlags: $T }
+    };
+    // the inner type by name, from the `__inner` option, or none
+    (@also_as_inner [__inner = $Inner:ident $($_:tt)*] $($info:tt)*) => {
+        $crate::__tiny_bit_flags! { @also_as [$Inner] $($info)* }
+    };
+    (@also_as_inner [] $($info:tt)*) => {
+        $crate::__tiny_bit_flags! { @also_as [_] $($info)* }
+    };
+    (@also_as_inner [$_:tt $($rest:tt)*] $($info:tt)*) => {
+        $crate::__tiny_bit_flags! { @also_as_inner [$($rest)*] $($info)* }
+    };
+    (@also_as $inner:tt [$(,)?] $($info:tt)*) => {};
+    (
+        @also_as [$Inner:tt] [$Other:ident: $U:ident $(, $($rest:tt)*)?]
+        $body:tt [$vis:vis] $BitFlags:ident: $T:ty
+    ) => {
+        $crate::__tiny_bit_flags! { @also_as_struct $body [$vis] $BitFlags: $T, $Other: $U }
+        $crate::__tiny_bit_flags! {
+            @also_as_width $Inner { @also_as_width $U { @also_as_from $BitFlags: $T, $Other: $U; } }
+        }
+        $crate::__tiny_bit_flags! { @also_as [$Inner] [$($($rest)*)?] $body [$vis] $BitFlags: $T }
+    };
+    (
+        @also_as [$Inner:tt] [$Other:ident: $U:ty $(, $($rest:tt)*)?]
+        $body:tt [$vis:vis] $BitFlags:ident: $T:ty
+    ) => {
+        $crate::__tiny_bit_flags! { @also_as_struct $body [$vis] $BitFlags: $T, $Other: $U }
+        $crate::__tiny_bit_flags! { @also_as_from $BitFlags: $T, $Other: $U; _ _ }
+        $crate::__tiny_bit_flags! { @also_as [$Inner] [$($($rest)*)?] $body [$vis] $BitFlags: $T }
+    };
+    (@also_as_struct $body:tt [$vis:vis] $BitFlags:ident: $T:ty, $Other:ident: $U:ty) => {
+        $crate::tiny_bit_flags! {
+            #[doc = ::core::concat!("The flags of [`", ::core::stringify!($BitFlags), "`] in `", ::core::stringify!($U), "`.")]
+            $vis struct $Other: $U $body
+        }
+    };
+    // the width of an integer type by name, in bytes as repeated `x`, or
+    // `_` if unknown, e.g. of `usize` or an alias, appended to the tokens
+    (@also_as_width $Int:tt { $($k:tt)* } $($acc:tt)*) => {
+        $crate::__tiny_bit_flags! { @also_as_width_of $Int { $($k)* } $($acc)* }
+    };
+    (@also_as_width_of u8 { $($k:tt)* } $($acc:tt)*) => { $crate::__tiny_bit_flags! { $($k)* $($acc)* [x] } };
+    (@also_as_width_of i8 { $($k:tt)* } $($acc:tt)*) => { $crate::__tiny_bit_flags! { $($k)* $($acc)* [x] } };
+    (@also_as_width_of u16 { $($k:tt)* } $($acc:tt)*) => { $crate::__tiny_bit_flags! { $($k)* $($acc)* [x x] } };
+    (@also_as_width_of i16 { $($k:tt)* } $($acc:tt)*) => { $crate::__tiny_bit_flags! { $($k)* $($acc)* [x x] } };
+    (@also_as_width_of u32 { $($k:tt)* } $($acc:tt)*) => { $crate::__tiny_bit_flags! { $($k)* $($acc)* [x x x x] } };
+    (@also_as_width_of i32 { $($k:tt)* } $($acc:tt)*) => { $crate::__tiny_bit_flags! { $($k)* $($acc)* [x x x x] } };
+    (@also_as_width_of u64 { $($k:tt)* } $($acc:tt)*) => { $crate::__tiny_bit_flags! { $($k)* $($acc)* [x x x x x x x x] } };
+    (@also_as_width_of i64 { $($k:tt)* } $($acc:tt)*) => { $crate::__tiny_bit_flags! { $($k)* $($acc)* [x x x x x x x x] } };
+    (@also_as_width_of u128 { $($k:tt)* } $($acc:tt)*) => {
+        $crate::__tiny_bit_flags! { $($k)* $($acc)* [x x x x x x x x x x x x x x x x] }
+    };
+    (@also_as_width_of i128 { $($k:tt)* } $($acc:tt)*) => {
+        $crate::__tiny_bit_flags! { $($k)* $($acc)* [x x x x x x x x x x x x x x x x] }
+    };
+    (@also_as_width_of $_:tt { $($k:tt)* } $($acc:tt)*) => { $crate::__tiny_bit_flags! { $($k)* $($acc)* _ } };
+    // `From` to the wider type, or either if of the same width, which
+    // keeps every bit, and `TryFrom` to the narrower type, or both if a
+    // width is unknown, which fails if any set bit is lost
+    (@also_as_from $BitFlags:ident: $T:ty, $Other:ident: $U:ty; [$($t:tt)*] [$($u:tt)*]) => {
+        $crate::__tiny_bit_flags! { @also_as_cmp $BitFlags: $T, $Other: $U [$($t)*] [$($u)*] }
+    };
+    (@also_as_from $BitFlags:ident: $T:ty, $Other:ident: $U:ty; $t:tt $u:tt) => {
+        $crate::__tiny_bit_flags! { @also_as_try $Other: $U => $BitFlags: $T }
+        $crate::__tiny_bit_flags! { @also_as_try $BitFlags: $T => $Other: $U }
+    };
+    (@also_as_cmp $BitFlags:ident: $T:ty, $Other:ident: $U:ty [x $($t:tt)*] [x $($u:tt)*]) => {
+        $crate::__tiny_bit_flags! { @also_as_cmp $BitFlags: $T, $Other: $U [$($t)*] [$($u)*] }
+    };
+    (@also_as_cmp $BitFlags:ident: $T:ty, $Other:ident: $U:ty [] []) => {
+        $crate::__tiny_bit_flags! { @also_as_into $Other: $U => $BitFlags: $T }
+        $crate::__tiny_bit_flags! { @also_as_into $BitFlags: $T => $Other: $U }
+    };
+    (@also_as_cmp $BitFlags:ident: $T:ty, $Other:ident: $U:ty [$($t:tt)+] []) => {
+        $crate::__tiny_bit_flags! { @also_as_into $Other: $U => $BitFlags: $T }
+        $crate::__tiny_bit_flags! { @also_as_try $BitFlags: $T => $Other: $U }
+    };
+    (@also_as_cmp $BitFlags:ident: $T:ty, $Other:ident: $U:ty [] [$($u:tt)+]) => {
+        $crate::__tiny_bit_flags! { @also_as_try $Other: $U => $BitFlags: $T }
+        $crate::__tiny_bit_flags! { @also_as_into $BitFlags: $T => $Other: $U }
+    };
+    // the same bits, as the declarations are the same, and the unknown
+    // ones too, zero-extended for a signed `From`
+    (@also_as_into $From:ident: $F:ty => $To:ident: $T:ty) => {
+        impl ::core::convert::From<$From> for $To {
+            fn from(flags: $From) -> Self {
+                let mask: $T = if <$F>::BITS >= <$T>::BITS { !0 } else { !(!0 << <$F>::BITS) };
+                Self((flags.0 as $T) & mask)
+            }
+        }
+    };
+    (@also_as_try $From:ident: $F:ty => $To:ident: $T:ty) => {
+        impl ::core::convert::TryFrom<$From> for $To {
+            type Error = $crate::UnknownBitsError;
+
+            // only if no bit is lost, which can be unknown ones only, as
+            // the flags fit in both types
+            fn try_from(flags: $From) -> ::core::result::Result<Self, Self::Error> {
+                let bits = flags.0 as $T;
+                let mask: $F = if <$T>::BITS >= <$F>::BITS { !0 } else { !(!0 << <$T>::BITS) };
+                if (bits as $F) & mask == flags.0 {
+                    ::core::result::Result::Ok(Self(bits))
+                } else {
+                    ::core::result::Result::Err($crate::UnknownBitsError(()))
+                }
+            }
+        }
+    };
+    // option: `as_ref`
+    (@option as_ref; [$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        impl ::core::convert::AsRef<$T> for $BitFlags {
+            fn as_ref(&self) -> &$T {
+                &self.0
+            }
+        }
+
+        // consistent with `Eq` and `Hash`, which are of the inner value only
+        impl ::core::borrow::Borrow<$T> for $BitFlags {
+            fn borrow(&self) -> &$T {
+                &self.0
+            }
+        }
+    };
+    // option: `bitfield_struct`
+    (@option bitfield_struct; [$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        impl $BitFlags {
+            // conversions that `bitfield-struct` looks for on field types
+            $vis const fn into_bits(self) -> $T {
+                self.0
+            }
+            $vis const fn from_bits(bits: $T) -> Self {
+                Self(bits)
+            }
+        }
+    };
+    // option: `atomic`
+    (
+        @option atomic; $opts:tt
+        $vis:vis struct $BitFlags:ident: $T:ty {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                $(pub $(($($fvis:tt)*))?)? const $Flag:tt = $value:expr;
+            )*
+        }
+    ) => {
+        $crate::__paste! {
+            #[doc = ::core::concat!("[`", ::core::stringify!($BitFlags), "`] in an atomic integer, with the per-flag methods on `&self`.")]
+            #[derive(::core::default::Default)]
+            $vis struct [<Atomic $BitFlags>](<$T as $crate::__Atomic>::Atomic);
+
+            impl [<Atomic $BitFlags>] {
+                $vis const fn new(flags: $BitFlags) -> Self {
+                    Self(<<$T as $crate::__Atomic>::Atomic>::new(flags.0))
+                }
+
+                $vis fn load(&self, order: ::core::sync::atomic::Ordering) -> $BitFlags {
+                    $BitFlags(self.0.load(order))
+                }
+                $vis fn store(&self, flags: $BitFlags, order: ::core::sync::atomic::Ordering) {
+                    self.0.store(flags.0, order)
+                }
+                $vis fn swap(&self, flags: $BitFlags, order: ::core::sync::atomic::Ordering) -> $BitFlags {
+                    $BitFlags(self.0.swap(flags.0, order))
+                }
+                $vis fn into_inner(self) -> $BitFlags {
+                    $BitFlags(self.0.into_inner())
+                }
+
+                // set or clear the bits, and return the previous value
+                $vis fn fetch_or(&self, flags: $BitFlags, order: ::core::sync::atomic::Ordering) -> $BitFlags {
+                    $BitFlags(self.0.fetch_or(flags.0, order))
+                }
+                $vis fn fetch_and(&self, flags: $BitFlags, order: ::core::sync::atomic::Ordering) -> $BitFlags {
+                    $BitFlags(self.0.fetch_and(flags.0, order))
+                }
+
+                // as by the atomic integer, but of the struct, e.g. for
+                // transitions of lock-free state machines
+                $vis fn compare_exchange(
+                    &self,
+                    current: $BitFlags,
+                    new: $BitFlags,
+                    success: ::core::sync::atomic::Ordering,
+                    failure: ::core::sync::atomic::Ordering,
+                ) -> ::core::result::Result<$BitFlags, $BitFlags> {
+                    match self.0.compare_exchange(current.0, new.0, success, failure) {
+                        ::core::result::Result::Ok(bits) => ::core::result::Result::Ok($BitFlags(bits)),
+                        ::core::result::Result::Err(bits) => ::core::result::Result::Err($BitFlags(bits)),
+                    }
+                }
+                $vis fn compare_exchange_weak(
+                    &self,
+                    current: $BitFlags,
+                    new: $BitFlags,
+                    success: ::core::sync::atomic::Ordering,
+                    failure: ::core::sync::atomic::Ordering,
+                ) -> ::core::result::Result<$BitFlags, $BitFlags> {
+                    match self.0.compare_exchange_weak(current.0, new.0, success, failure) {
+                        ::core::result::Result::Ok(bits) => ::core::result::Result::Ok($BitFlags(bits)),
+                        ::core::result::Result::Err(bits) => ::core::result::Result::Err($BitFlags(bits)),
+                    }
+                }
+                $vis fn fetch_update(
+                    &self,
+                    set_order: ::core::sync::atomic::Ordering,
+                    fetch_order: ::core::sync::atomic::Ordering,
+                    mut f: impl ::core::ops::FnMut($BitFlags) -> ::core::option::Option<$BitFlags>,
+                ) -> ::core::result::Result<$BitFlags, $BitFlags> {
+                    match self.0.fetch_update(set_order, fetch_order, |bits| f($BitFlags(bits)).map(|flags| flags.0)) {
+                        ::core::result::Result::Ok(bits) => ::core::result::Result::Ok($BitFlags(bits)),
+                        ::core::result::Result::Err(bits) => ::core::result::Result::Err($BitFlags(bits)),
+                    }
+                }
+
+                $(
+                    $crate::__tiny_bit_flags! {
+                        @flag_vis [$(pub $(($($fvis)*))?)?] [$vis] @atomic_items $BitFlags $Flag
+                    }
+                )*
+            }
+        }
+    };
+    (@atomic_items [$vis:vis] $BitFlags:ident $Flag:tt) => {
+        $crate::__paste! {
+            $vis fn [<is_ $Flag:lower>](&self, order: ::core::sync::atomic::Ordering) -> bool {
+                let value = const { $crate::__tiny_bit_flags!(@value $BitFlags $Flag) };
+                self.0.load(order) & value == value
+            }
+            $vis fn [<set_ $Flag:lower>](&self, order: ::core::sync::atomic::Ordering) {
+                let value = const { $crate::__tiny_bit_flags!(@value $BitFlags $Flag) };
+                self.0.fetch_or(value, order);
+            }
+            $vis fn [<clear_ $Flag:lower>](&self, order: ::core::sync::atomic::Ordering) {
+                let value = const { $crate::__tiny_bit_flags!(@value $BitFlags $Flag) };
+                self.0.fetch_and(!value, order);
+            }
+            // whether this call has changed the bit, e.g. to take a one-shot
+            // job or a lock of the flag
+            $vis fn [<set_ $Flag:lower _if_clear>](&self, order: ::core::sync::atomic::Ordering) -> bool {
+                let value = const { $crate::__tiny_bit_flags!(@value $BitFlags $Flag) };
+                self.0.fetch_or(value, order) & value != value
+            }
+            $vis fn [<clear_ $Flag:lower _if_set>](&self, order: ::core::sync::atomic::Ordering) -> bool {
+                let value = const { $crate::__tiny_bit_flags!(@value $BitFlags $Flag) };
+                self.0.fetch_and(!value, order) & value == value
+            }
+        }
+    };
+    // option: `global`
+    (
+        @option global; $opts:tt
+        $vis:vis struct $BitFlags:ident: $T:ty {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                $(pub $(($($fvis:tt)*))?)? const $Flag:tt = $value:expr;
+            )*
+        }
+    ) => {
+        impl $BitFlags {
+            // the process-wide value, in an atomic integer, from no bits set
+            fn __global() -> &'static <$T as $crate::__Atomic>::Atomic {
+                static GLOBAL: <$T as $crate::__Atomic>::Atomic = <<$T as $crate::__Atomic>::Atomic>::new(0);
+                &GLOBAL
+            }
+
+            $vis fn global_load() -> Self {
+                Self(Self::__global().load(::core::sync::atomic::Ordering::SeqCst))
+            }
+            $vis fn global_store(flags: Self) {
+                Self::__global().store(flags.0, ::core::sync::atomic::Ordering::SeqCst)
+            }
+
+            $(
+                $crate::__tiny_bit_flags! {
+                    @flag_vis [$(pub $(($($fvis)*))?)?] [$vis] @global_items $BitFlags $Flag
+                }
+            )*
+        }
+    };
+    (@global_items [$vis:vis] $BitFlags:ident $Flag:tt) => {
+        $crate::__paste! {
+            $vis fn [<global_is_ $Flag:lower>]() -> bool {
+                let value = const { $crate::__tiny_bit_flags!(@value $BitFlags $Flag) };
+                Self::__global().load(::core::sync::atomic::Ordering::SeqCst) & value == value
+            }
+            $vis fn [<global_set_ $Flag:lower>]() {
+                let value = const { $crate::__tiny_bit_flags!(@value $BitFlags $Flag) };
+                Self::__global().fetch_or(value, ::core::sync::atomic::Ordering::SeqCst);
+            }
+            $vis fn [<global_clear_ $Flag:lower>]() {
+                let value = const { $crate::__tiny_bit_flags!(@value $BitFlags $Flag) };
+                Self::__global().fetch_and(!value, ::core::sync::atomic::Ordering::SeqCst);
+            }
+        }
+    };
+    // option: `bit_band`
+    (
+        @option bit_band; $opts:tt
+        $vis:vis struct $BitFlags:ident: $T:ty {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                $(pub $(($($fvis:tt)*))?)? const $Flag:tt = $value:expr;
+            )*
+        }
+    ) => {
+        const _: () = ::core::assert!(<$T>::BITS <= 32, "`bit_band` needs a type of at most 32 bits");
+        $(
+            const _: () = ::core::assert!(
+                $crate::__tiny_bit_flags!(@value $BitFlags $Flag).count_ones() == 1,
+                ::core::concat!("flag `", ::core::stringify!($Flag), "` of `", ::core::stringify!($BitFlags), "` is not one bit for `bit_band`"),
+            );
+        )*
+
+        impl $BitFlags {
+            $(
+                $crate::__tiny_bit_flags! {
+                    @flag_vis [$(pub $(($($fvis)*))?)?] [$vis] @bit_band_items $BitFlags $Flag
+                }
+            )*
+        }
+    };
+    (@bit_band_items [$vis:vis] $BitFlags:ident $Flag:tt) => {
+        $crate::__paste! {
+            // the bit-band alias address of the flag in the register at the
+            // address, whose word is written with 1 or 0 to set or clear it
+            $vis const fn [<bit_band_ $Flag:lower>](register: usize) -> usize {
+                let value = $crate::__tiny_bit_flags!(@value $BitFlags $Flag);
+                $crate::__bit_band(register, value.trailing_zeros())
+            }
+        }
+    };
+    // option: `cell`
+    (
+        @option cell; $opts:tt
+        $vis:vis struct $BitFlags:ident: $T:ty {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                $(pub $(($($fvis:tt)*))?)? const $Flag:tt = $value:expr;
+            )*
+        }
+    ) => {
+        $crate::__paste! {
+            #[doc = ::core::concat!("[`", ::core::stringify!($BitFlags), "`] in a `Cell`, with the per-flag methods on `&self`.")]
+            #[derive(::core::default::Default)]
+            $vis struct [<Cell $BitFlags>](::core::cell::Cell<$T>);
+
+            impl [<Cell $BitFlags>] {
+                $vis const fn new(flags: $BitFlags) -> Self {
+                    Self(::core::cell::Cell::new(flags.0))
+                }
+
+                $vis fn get(&self) -> $BitFlags {
+                    $BitFlags(self.0.get())
+                }
+                $vis fn set(&self, flags: $BitFlags) {
+                    self.0.set(flags.0)
+                }
+                $vis fn into_inner(self) -> $BitFlags {
+                    $BitFlags(self.0.into_inner())
+                }
+
+                $(
+                    $crate::__tiny_bit_flags! {
+                        @flag_vis [$(pub $(($($fvis)*))?)?] [$vis] @cell_items $BitFlags $Flag
+                    }
+                )*
+            }
+        }
+    };
+    (@cell_items [$vis:vis] $BitFlags:ident $Flag:tt) => {
+        $crate::__paste! {
+            $vis fn [<is_ $Flag:lower>](&self) -> bool {
+                let value = const { $crate::__tiny_bit_flags!(@value $BitFlags $Flag) };
+                self.0.get() & value == value
+            }
+            $vis fn [<set_ $Flag:lower>](&self) {
+                let value = const { $crate::__tiny_bit_flags!(@value $BitFlags $Flag) };
+                self.0.set(self.0.get() | value)
+            }
+            $vis fn [<clear_ $Flag:lower>](&self) {
+                let value = const { $crate::__tiny_bit_flags!(@value $BitFlags $Flag) };
+                self.0.set(self.0.get() & !value)
+            }
+        }
+    };
+
+    // option: `codes = "..."`
+    (@option codes = $codes:literal; [$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        impl $BitFlags {
+            // a character for each flag, in declaration order
+            $vis const FLAG_CODES: &'static str = $codes;
+
+            // the codes of the flags, or `-` for unset flags, e.g. `-x`
+            $vis fn codes(&self) -> impl ::core::fmt::Display {
+                struct Codes($T);
+
+                impl ::core::fmt::Display for Codes {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        use ::core::iter::Iterator as _;
+                        for (code, &value) in $BitFlags::FLAG_CODES.bytes().zip($BitFlags::FLAG_VALUES) {
+                            let c = if self.0 & value == value { code as char } else { '-' };
+                            ::core::fmt::Write::write_char(f, c)?;
+                        }
+                        ::core::result::Result::Ok(())
+                    }
+                }
+
+                Codes(self.0)
+            }
+
+            // the reverse of `codes()`, with a code or `-` for each flag
+            $vis fn from_codes(s: &str) -> ::core::result::Result<Self, $crate::ParseError> {
+                use ::core::iter::Iterator as _;
+                let err = |i: usize, len: usize| $crate::ParseError::__at(s, i, len, Self::FLAG_NAMES);
+                let mut chars = s.char_indices();
+                let mut bits = 0;
+                for (code, &value) in Self::FLAG_CODES.bytes().zip(Self::FLAG_VALUES) {
+                    match chars.next() {
+                        ::core::option::Option::Some((_, '-')) => {}
+                        ::core::option::Option::Some((_, c)) if c == code as char => bits |= value,
+                        ::core::option::Option::Some((i, c)) => return ::core::result::Result::Err(err(i, c.len_utf8())),
+                        ::core::option::Option::None => return ::core::result::Result::Err(err(s.len(), 0)),
+                    }
+                }
+                match chars.next() {
+                    ::core::option::Option::Some((i, _)) => ::core::result::Result::Err(err(i, s.len() - i)),
+                    ::core::option::Option::None => ::core::result::Result::Ok(Self(bits)),
+                }
+            }
+        }
+
+        const _: () = {
+            let codes = $BitFlags::FLAG_CODES.as_bytes();
+            ::core::assert!(
+                codes.len() == $BitFlags::NUM_FLAGS,
+                ::core::concat!("the codes of `", ::core::stringify!($BitFlags), "` are not one for each flag"),
+            );
+            let mut i = 0;
+            while i < codes.len() {
+                ::core::assert!(
+                    codes[i].is_ascii_graphic() && codes[i] != b'-',
+                    ::core::concat!("a code of `", ::core::stringify!($BitFlags), "` is `-` or not a printable ASCII character"),
+                );
+                i += 1;
+            }
+        };
+    };
+    // option: `async_graphql`
+    (@option async_graphql; $($info:tt)*) => {
+        $crate::__tiny_bit_flags_async_graphql! { $($info)* }
+    };
+    // option: `borsh`
+    (@option borsh; $($info:tt)*) => {
+        $crate::__tiny_bit_flags_borsh! { $($info)* }
+    };
+    // option: `critical_section`
+    (@option critical_section; $($info:tt)*) => {
+        $crate::__tiny_bit_flags_critical_section! { $($info)* }
+    };
+    // option: `redis`
+    (@option redis; $($info:tt)*) => {
+        $crate::__tiny_bit_flags_redis! { $($info)* }
+    };
+    // option: `rusqlite`
+    (@option rusqlite; $($info:tt)*) => {
+        $crate::__tiny_bit_flags_rusqlite! { $($info)* }
+    };
+    // option: `rusqlite_strict`, used by `rusqlite`
+    (@option rusqlite_strict; $($info:tt)*) => {};
+    // option: `registry`
+    (@option registry; $($info:tt)*) => {
+        $crate::__tiny_bit_flags_registry! { $($info)* }
+    };
+    // option: `ufmt`
+    (@option ufmt; $($info:tt)*) => {
+        $crate::__tiny_bit_flags_ufmt! { $($info)* }
+    };
+    // option: `uniffi`
+    (@option uniffi; $($info:tt)*) => {
+        $crate::__tiny_bit_flags_uniffi! { $($info)* }
+    };
+    // option: `utoipa`
+    (@option utoipa; $($info:tt)*) => {
+        $crate::__tiny_bit_flags_utoipa! { $($info)* }
+    };
+    // option: `map`
+    (@option map; [$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        $crate::__paste! {
+            #[doc = ::core::concat!("A value for each flag of [`", ::core::stringify!($BitFlags), "`], in declaration order.")]
+            #[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::cmp::PartialEq, ::core::cmp::Eq, ::core::hash::Hash)]
+            $vis struct [<$BitFlags Map>]<V>([V; $BitFlags::NUM_FLAGS]);
+
+            impl<V> [<$BitFlags Map>]<V> {
+                $vis const fn new(values: [V; $BitFlags::NUM_FLAGS]) -> Self {
+                    Self(values)
+                }
+                $vis fn from_fn(mut f: impl ::core::ops::FnMut($BitFlags) -> V) -> Self {
+                    Self(::core::array::from_fn(|i| f($BitFlags($BitFlags::FLAG_VALUES[i]))))
+                }
+
+                // the value of the flag, if the key is exactly one flag
+                fn position(flag: $BitFlags) -> ::core::option::Option<usize> {
+                    use ::core::iter::Iterator as _;
+                    $BitFlags::FLAG_VALUES.iter().position(|&value| value == flag.0)
+                }
+                $vis fn get(&self, flag: $BitFlags) -> ::core::option::Option<&V> {
+                    Self::position(flag).map(|i| &self.0[i])
+                }
+                $vis fn get_mut(&mut self, flag: $BitFlags) -> ::core::option::Option<&mut V> {
+                    Self::position(flag).map(|i| &mut self.0[i])
+                }
+                // return the old value, or `None` if the key is not one flag
+                $vis fn set(&mut self, flag: $BitFlags, value: V) -> ::core::option::Option<V> {
+                    self.get_mut(flag).map(|old| ::core::mem::replace(old, value))
+                }
+
+                // iterators over the flags and values, in declaration order
+                $vis fn iter(&self) -> impl ::core::iter::Iterator<Item = ($BitFlags, &V)> {
+                    use ::core::iter::Iterator as _;
+                    $BitFlags::all_flags().zip(self.0.iter())
+                }
+                $vis fn iter_mut(&mut self) -> impl ::core::iter::Iterator<Item = ($BitFlags, &mut V)> {
+                    use ::core::iter::Iterator as _;
+                    $BitFlags::all_flags().zip(self.0.iter_mut())
+                }
+            }
+
+            impl<V: ::core::default::Default> ::core::default::Default for [<$BitFlags Map>]<V> {
+                fn default() -> Self {
+                    Self::from_fn(|_| V::default())
+                }
+            }
+
+            // panic if the key is not exactly one flag
+            impl<V> ::core::ops::Index<$BitFlags> for [<$BitFlags Map>]<V> {
+                type Output = V;
+                fn index(&self, flag: $BitFlags) -> &V {
+                    self.get(flag).expect("not a flag")
+                }
+            }
+            impl<V> ::core::ops::IndexMut<$BitFlags> for [<$BitFlags Map>]<V> {
+                fn index_mut(&mut self, flag: $BitFlags) -> &mut V {
+                    self.get_mut(flag).expect("not a flag")
+                }
+            }
+        }
+    };
+    // option: `modular_bitfield`
+    (@option modular_bitfield; $($info:tt)*) => {
+        $crate::__tiny_bit_flags_modular_bitfield! { $($info)* }
+    };
+    // option: `no_derives`, used by `@parse`
+    (@option no_derives; $($info:tt)*) => {};
+    // option: `non_zero`
+    (@option non_zero; [$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        $crate::__paste! {
+            #[doc = ::core::concat!("[`", ::core::stringify!($BitFlags), "`] with some bit set, whose `Option` takes no more room.")]
+            #[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::marker::Copy, ::core::cmp::PartialEq, ::core::cmp::Eq, ::core::hash::Hash)]
+            $vis struct [<$BitFlags NonZero>](::core::num::NonZero<$T>);
+
+            impl [<$BitFlags NonZero>] {
+                // `None` if no bit is set
+                $vis const fn new(flags: $BitFlags) -> ::core::option::Option<Self> {
+                    match ::core::num::NonZero::new(flags.0) {
+                        ::core::option::Option::Some(bits) => ::core::option::Option::Some(Self(bits)),
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    }
+                }
+                $vis const fn get(self) -> $BitFlags {
+                    $BitFlags(self.0.get())
+                }
+            }
+
+            impl ::core::convert::From<[<$BitFlags NonZero>]> for $BitFlags {
+                fn from(flags: [<$BitFlags NonZero>]) -> Self {
+                    flags.get()
+                }
+            }
+
+            // `None` as no bit set
+            impl ::core::convert::From<::core::option::Option<[<$BitFlags NonZero>]>> for $BitFlags {
+                fn from(flags: ::core::option::Option<[<$BitFlags NonZero>]>) -> Self {
+                    flags.map_or(Self(0), |flags| flags.get())
+                }
+            }
+        }
+    };
+    // option: `observed`
+    (
+        @option observed; $opts:tt
+        $vis:vis struct $BitFlags:ident: $T:ty {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                $(pub $(($($fvis:tt)*))?)? const $Flag:tt = $value:expr;
+            )*
+        }
+    ) => {
+        $crate::__paste! {
+            #[doc = ::core::concat!("[`", ::core::stringify!($BitFlags), "`] that calls back with the name, and the old and new states, of each flag changed by its methods.")]
+            $vis struct [<Observed $BitFlags>]<F: ::core::ops::FnMut(&'static str, bool, bool)> {
+                flags: $BitFlags,
+                on_change: F,
+            }
+
+            impl<F: ::core::ops::FnMut(&'static str, bool, bool)> [<Observed $BitFlags>]<F> {
+                $vis fn new(flags: $BitFlags, on_change: F) -> Self {
+                    Self { flags, on_change }
+                }
+
+                $vis fn flags(&self) -> &$BitFlags {
+                    &self.flags
+                }
+                $vis fn into_inner(self) -> $BitFlags {
+                    self.flags
+                }
+
+                // set the bits, and call back if any bit of the flag is changed
+                fn update(&mut self, name: &'static str, value: $T, bits: $T) {
+                    let old = self.flags.0;
+                    self.flags.0 = (old & !value) | bits;
+                    if self.flags.0 != old {
+                        (self.on_change)(name, old & value == value, bits == value);
+                    }
+                }
+
+                $(
+                    $crate::__tiny_bit_flags! {
+                        @flag_vis [$(pub $(($($fvis)*))?)?] [$vis] @observed_items $BitFlags $Flag
+                    }
+                )*
+            }
+        }
+    };
+    (@observed_items [$vis:vis] $BitFlags:ident $Flag:tt) => {
+        $crate::__paste! {
+            $vis fn [<is_ $Flag:lower>](&self) -> bool {
+                let value = const { $crate::__tiny_bit_flags!(@value $BitFlags $Flag) };
+                self.flags.0 & value == value
+            }
+            $vis fn [<set_ $Flag:lower>](&mut self) {
+                let value = const { $crate::__tiny_bit_flags!(@value $BitFlags $Flag) };
+                self.update(::core::stringify!($Flag), value, value);
+            }
+            $vis fn [<clear_ $Flag:lower>](&mut self) {
+                let value = const { $crate::__tiny_bit_flags!(@value $BitFlags $Flag) };
+                self.update(::core::stringify!($Flag), value, 0);
+            }
+        }
+    };
+
+    // option: `patch`
+    (
+        @option patch; $opts:tt
+        $vis:vis struct $BitFlags:ident: $T:ty {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                $(pub $(($($fvis:tt)*))?)? const $Flag:tt = $value:expr;
+            )*
+        }
+    ) => {
+        $crate::__paste! {
+            #[doc = ::core::concat!("Changes to [`", ::core::stringify!($BitFlags), "`]: the bits to set and the bits to clear.")]
+            #[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::marker::Copy, ::core::default::Default, ::core::cmp::PartialEq, ::core::cmp::Eq, ::core::hash::Hash)]
+            $vis struct [<$BitFlags Patch>] {
+                $vis set: $T,
+                $vis clear: $T,
+            }
+
+            impl [<$BitFlags Patch>] {
+                // no change
+                $vis const fn new() -> Self {
+                    Self { set: 0, clear: 0 }
+                }
+
+                // the changes from `old` to `new`
+                $vis const fn between(old: &$BitFlags, new: &$BitFlags) -> Self {
+                    Self { set: new.0 & !old.0, clear: old.0 & !new.0 }
+                }
+
+                // set and clear the bits, keeping the others
+                $vis const fn apply(&self, flags: &mut $BitFlags) {
+                    flags.0 = (flags.0 & !self.clear) | self.set;
+                }
+
+                $(
+                    $crate::__tiny_bit_flags! {
+                        @flag_vis [$(pub $(($($fvis)*))?)?] [$vis] @patch_items $BitFlags $Flag
+                    }
+                )*
+            }
+        }
+    };
+    (@patch_items [$vis:vis] $BitFlags:ident $Flag:tt) => {
+        $crate::__paste! {
+            // the later change of a flag wins
+            $vis const fn [<set_ $Flag:lower>](mut self) -> Self {
+                let value = const { $crate::__tiny_bit_flags!(@value $BitFlags $Flag) };
+                self.set |= value;
+                self.clear &= !value;
+                self
+            }
+            $vis const fn [<clear_ $Flag:lower>](mut self) -> Self {
+                let value = const { $crate::__tiny_bit_flags!(@value $BitFlags $Flag) };
+                self.clear |= value;
+                self.set &= !value;
+                self
+            }
+        }
+    };
+    // option: `reserved = mask`
+    (@option reserved = $mask:tt; [$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        impl $BitFlags {
+            // bits that must not be written, and so must not be of any flag
+            $vis const RESERVED_BITS: $T = $mask;
+        }
+
+        const _: () = {
+            let mut i = 0;
+            while i < $BitFlags::NUM_FLAGS {
+                ::core::assert!(
+                    $BitFlags::FLAG_VALUES[i] & $BitFlags::RESERVED_BITS == 0,
+                    ::core::concat!("a flag of `", ::core::stringify!($BitFlags), "` has reserved bits"),
+                );
+                i += 1;
+            }
+        };
+    };
+    // option: `width = bits`
+    (@option width = $width:tt; [$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        impl $BitFlags {
+            // the logical width, out of the inner type, and its bits
+            $vis const WIDTH: u32 = $width;
+            $vis const WIDTH_MASK: $T = if $width >= <$T>::BITS { !0 } else { !(!0 << $width) };
+        }
+
+        const _: () = {
+            ::core::assert!(
+                $BitFlags::WIDTH > 0 && $BitFlags::WIDTH <= <$T>::BITS,
+                ::core::concat!("the width of `", ::core::stringify!($BitFlags), "` is out of its inner type"),
+            );
+            let mut i = 0;
+            while i < $BitFlags::NUM_FLAGS {
+                ::core::assert!(
+                    $BitFlags::FLAG_VALUES[i] & !$BitFlags::WIDTH_MASK == 0,
+                    ::core::concat!("a flag of `", ::core::stringify!($BitFlags), "` is out of the width"),
+                );
+                i += 1;
+            }
+        };
+    };
+    // option: `dense`
+    (@option dense; [$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        const _: () = {
+            let mut all: $T = 0;
+            let mut i = 0;
+            while i < $BitFlags::NUM_FLAGS {
+                all |= $BitFlags::FLAG_VALUES[i];
+                i += 1;
+            }
+            // the bits from bit 0 up are all set, and none above
+            ::core::assert!(
+                all & all.wrapping_add(1) == 0,
+                ::core::concat!("the flags of `", ::core::stringify!($BitFlags), "` have a gap"),
+            );
+        };
+    };
+    // option: `scoped`
+    (@option scoped; [$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        $crate::__paste! {
+            impl $BitFlags {
+                // set the bits until the guard is dropped
+                $vis fn scoped(&mut self, bits: $T) -> [<$BitFlags Guard>]<'_> {
+                    let saved = self.0 & bits;
+                    self.0 |= bits;
+                    [<$BitFlags Guard>] { flags: self, bits, saved }
+                }
+            }
+
+            #[doc = ::core::concat!("Guard of [`", ::core::stringify!($BitFlags), "::scoped()`], which restores the bits when dropped.")]
+            $vis struct [<$BitFlags Guard>]<'a> {
+                flags: &'a mut $BitFlags,
+                bits: $T,
+                saved: $T,
+            }
+
+            impl ::core::ops::Deref for [<$BitFlags Guard>]<'_> {
+                type Target = $BitFlags;
+                fn deref(&self) -> &$BitFlags {
+                    self.flags
+                }
+            }
+
+            impl ::core::ops::DerefMut for [<$BitFlags Guard>]<'_> {
+                fn deref_mut(&mut self) -> &mut $BitFlags {
+                    self.flags
+                }
+            }
+
+            impl ::core::ops::Drop for [<$BitFlags Guard>]<'_> {
+                // only the bits of `scoped()`, keeping the other changes
+                fn drop(&mut self) {
+                    self.flags.0 = (self.flags.0 & !self.bits) | self.saved;
+                }
+            }
+        }
+    };
+
+    // option: `subset(Name: FLAG, ...)`
+    (
+        @option subset($Subset:ident: $($Flag:ident),* $(,)?); $opts:tt
+        $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }
+    ) => {
+        #[doc = ::core::concat!("Some flags of [`", ::core::stringify!($BitFlags), "`]: ", $("`", ::core::stringify!($Flag), "` ",)* "only.")]
+        #[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::marker::Copy, ::core::default::Default, ::core::cmp::PartialEq, ::core::cmp::Eq, ::core::hash::Hash)]
+        $vis struct $Subset($T);
+
+        impl $Subset {
+            const MASK: $T = 0 $(| $crate::__tiny_bit_flags!(@value $BitFlags $Flag))*;
+
+            $vis const fn bits(&self) -> $T {
+                self.0
+            }
+
+            $(
+                $crate::__tiny_bit_flags! { @subset_items [$vis] $BitFlags $Flag }
+            )*
+        }
+
+        // only the bits of the subset
+        impl ::core::convert::From<$BitFlags> for $Subset {
+            fn from(flags: $BitFlags) -> Self {
+                Self(flags.0 & Self::MASK)
+            }
+        }
+
+        impl ::core::convert::From<$Subset> for $BitFlags {
+            fn from(subset: $Subset) -> Self {
+                Self(subset.0)
+            }
+        }
+    };
+    (@subset_items [$vis:vis] $BitFlags:ident $Flag:tt) => {
+        $crate::__paste! {
+            $vis const fn [<is_ $Flag:lower>](&self) -> bool {
+                let value = const { $crate::__tiny_bit_flags!(@value $BitFlags $Flag) };
+                self.0 & value == value
+            }
+            $vis const fn [<set_ $Flag:lower>](&mut self) {
+                let value = const { $crate::__tiny_bit_flags!(@value $BitFlags $Flag) };
+                self.0 |= value
+            }
+            $vis const fn [<clear_ $Flag:lower>](&mut self) {
+                let value = const { $crate::__tiny_bit_flags!(@value $BitFlags $Flag) };
+                self.0 &= !value
+            }
+        }
+    };
+
+    // option: `tracked`
+    (
+        @option tracked; $opts:tt
+        $vis:vis struct $BitFlags:ident: $T:ty {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                $(pub $(($($fvis:tt)*))?)? const $Flag:tt = $value:expr;
+            )*
+        }
+    ) => {
+        $crate::__paste! {
+            #[doc = ::core::concat!("[`", ::core::stringify!($BitFlags), "`] that records the bits changed by its methods.")]
+            $vis struct [<Tracked $BitFlags>] {
+                flags: $BitFlags,
+                dirty: $T,
+            }
+
+            impl [<Tracked $BitFlags>] {
+                // no bits changed yet
+                $vis const fn new(flags: $BitFlags) -> Self {
+                    Self { flags, dirty: 0 }
+                }
+
+                $vis const fn flags(&self) -> &$BitFlags {
+                    &self.flags
+                }
+                $vis fn into_inner(self) -> $BitFlags {
+                    self.flags
+                }
+
+                // the bits changed since the last call, or since `new()`
+                $vis const fn take_dirty(&mut self) -> $BitFlags {
+                    let dirty = self.dirty;
+                    self.dirty = 0;
+                    $BitFlags(dirty)
+                }
+
+                $(
+                    $crate::__tiny_bit_flags! {
+                        @flag_vis [$(pub $(($($fvis)*))?)?] [$vis] @tracked_items $BitFlags $Flag
+                    }
+                )*
+            }
+        }
+    };
+    (@tracked_items [$vis:vis] $BitFlags:ident $Flag:tt) => {
+        $crate::__paste! {
+            $vis const fn [<is_ $Flag:lower>](&self) -> bool {
+                let value = const { $crate::__tiny_bit_flags!(@value $BitFlags $Flag) };
+                self.flags.0 & value == value
+            }
+            $vis const fn [<set_ $Flag:lower>](&mut self) {
+                let value = const { $crate::__tiny_bit_flags!(@value $BitFlags $Flag) };
+                self.dirty |= !self.flags.0 & value;
+                self.flags.0 |= value;
+            }
+            $vis const fn [<clear_ $Flag:lower>](&mut self) {
+                let value = const { $crate::__tiny_bit_flags!(@value $BitFlags $Flag) };
+                self.dirty |= self.flags.0 & value;
+                self.flags.0 &= !value;
+            }
+        }
+    };
+
+    // option: `view`
+    (
+        @option view; $opts:tt
+        $vis:vis struct $BitFlags:ident: $T:ty {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                $(pub $(($($fvis:tt)*))?)? const $Flag:tt = $value:expr;
+            )*
+        }
+    ) => {
+        $crate::__paste! {
+            impl $BitFlags {
+                // read-only access, e.g. for callbacks
+                $vis const fn view(&self) -> [<$BitFlags View>]<'_> {
+                    [<$BitFlags View>](self)
+                }
+            }
+
+            #[doc = ::core::concat!("Borrowed [`", ::core::stringify!($BitFlags), "`] with only the checking methods.")]
+            #[derive(::core::clone::Clone, ::core::marker::Copy)]
+            $vis struct [<$BitFlags View>]<'a>(&'a $BitFlags);
+
+            impl [<$BitFlags View>]<'_> {
+                $vis const fn bits(&self) -> $T {
+                    self.0.0
+                }
+
+                $(
+                    $crate::__tiny_bit_flags! {
+                        @flag_vis [$(pub $(($($fvis)*))?)?] [$vis] @view_items $BitFlags $Flag
+                    }
+                )*
+            }
+        }
+    };
+    (@view_items [$vis:vis] $BitFlags:ident $Flag:tt) => {
+        $crate::__paste! {
+            $vis const fn [<is_ $Flag:lower>](&self) -> bool {
+                let value = const { $crate::__tiny_bit_flags!(@value $BitFlags $Flag) };
+                self.0.0 & value == value
+            }
+        }
+    };
+
+    // the flag's own visibility if given, or the struct's, for `@$then`
+    (@flag_vis [] [$vis:vis] @$then:ident $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @$then [$vis] $($rest)* }
+    };
+    (@flag_vis [$($fvis:tt)+] [$vis:vis] @$then:ident $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @$then [$($fvis)+] $($rest)* }
+    };
+
+    // value of the flag, wherever its constant is
+    (@value $BitFlags:ident $Flag:tt) => {
+        match $BitFlags::from_name(::core::stringify!($Flag)) {
+            ::core::option::Option::Some(flag) => flag.0,
+            ::core::option::Option::None => ::core::panic!(::core::concat!("unknown flag: `", ::core::stringify!($Flag), "`")),
+        }
+    };
+
+    // option: `__none`, for a `NONE` declaration by `@none`
+    (
+        @option __none = {[$($attr:tt)*] [$($fvis:tt)*] $value:expr};
+        $opts:tt $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }
+    ) => {
+        $crate::__tiny_bit_flags! {
+            @fn_attrs $opts [] [] @impl_attrs $opts []
+            @none_items [$($attr)*] [$($fvis)*] $value; $opts $vis struct $BitFlags: $T
+        }
+    };
+    (
+        @none_items [$(#[$inline:meta])*] [$(#[$must_use:meta])*] [$(#[$impl_attr:meta])*]
+        [$($attr:tt)*] [$($fvis:tt)*] $value:expr; $opts:tt $vis:vis struct $BitFlags:ident: $T:ty
+    ) => {
+        $(#[$impl_attr])*
+        const _: () = ::core::assert!(
+            $BitFlags::is_none(&$BitFlags($value)),
+            ::core::concat!("`NONE` of `", ::core::stringify!($BitFlags), "` is not zero"),
+        );
+
+        $(#[$impl_attr])*
+        impl $BitFlags {
+            $crate::__tiny_bit_flags! { @flag_vis [$($fvis)*] [$vis] @none_const $opts [$($attr)*] $T = $value }
+
+            // whether no flags are set
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis const fn is_none(&self) -> bool {
+                self.0 == 0
+            }
+        }
+    };
+    (@none_const [$vis:vis] $opts:tt [$(#[$attr:meta])*] $T:ty = $value:expr) => {
+        $crate::__tiny_bit_flags! { @if typed_consts $opts {
+            $(#[$attr])*
+            $vis const NONE: Self = Self($value);
+        } {
+            $(#[$attr])*
+            $vis const NONE: $T = $value;
+        } }
+    };
+
+    // option: `kani`
+    (
+        @option kani; $opts:tt
+        $vis:vis struct $BitFlags:ident: $T:ty {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                $(pub $(($($fvis:tt)*))?)? const $Flag:tt = $value:expr;
+            )*
+        }
+    ) => {
+        $crate::__paste! {
+            // proof harnesses, run by `cargo kani`, and type-checked in
+            // other builds too, in which `any()` is never called
+            #[cfg_attr(not(kani), allow(dead_code))]
+            #[allow(non_snake_case)]
+            mod [<__kani_ $BitFlags>] {
+                use super::*;
+
+                #[cfg(kani)]
+                use kani::any;
+                #[cfg(not(kani))]
+                fn any<T>() -> T {
+                    ::core::unreachable!()
+                }
+
+                const RESERVED: $T = $crate::__tiny_bit_flags!(@get reserved $opts { 0 });
+                const KNOWN: $T = 0 $(| $crate::__tiny_bit_flags!(@value $BitFlags $Flag))*;
+
+                $(
+                    // setting and clearing change the flag only, if all the
+                    // families of the per-flag methods are generated
+                    $crate::__tiny_bit_flags! { @methods is $opts
+                        $crate::__tiny_bit_flags! { @methods set $opts
+                            $crate::__tiny_bit_flags! { @methods clear $opts
+                                #[cfg_attr(kani, kani::proof)]
+                                fn [<$Flag:lower>]() {
+                                    let value = $crate::__tiny_bit_flags!(@value $BitFlags $Flag);
+                                    let before: $T = any();
+                                    let mut flags = $BitFlags(before);
+                                    flags.[<set_ $Flag:lower>]();
+                                    ::core::assert!(flags.[<is_ $Flag:lower>]());
+                                    ::core::assert!(flags.0 == before | value);
+                                    flags.[<clear_ $Flag:lower>]();
+                                    ::core::assert!(!flags.[<is_ $Flag:lower>]());
+                                    ::core::assert!(flags.0 == before & !value);
+                                    ::core::assert!(value & RESERVED == 0);
+                                }
+                            }
+                        }
+                    }
+                )*
+
+                // the bit methods change the bit of a declared flag only
+                #[cfg_attr(kani, kani::proof)]
+                fn bits() {
+                    let before: $T = any();
+                    let n: u32 = any();
+                    let mut flags = $BitFlags(before);
+                    let changed = flags.insert_bit(n);
+                    ::core::assert!(flags.0 & !KNOWN == before & !KNOWN);
+                    let declared = n < <$T>::BITS && KNOWN & $BitFlags::bit(n) != 0;
+                    ::core::assert!(changed == declared && (!changed || flags.0 & $BitFlags::bit(n) != 0));
+                    flags.remove_bit(n);
+                    ::core::assert!(flags.0 & RESERVED == before & RESERVED);
+                }
+
+                // the known and unknown bits make up the value
+                #[cfg_attr(kani, kani::proof)]
+                fn masks() {
+                    let bits: $T = any();
+                    let (known, unknown) = $BitFlags(bits).split_known();
+                    ::core::assert!(known.0 | unknown == bits && known.0 & unknown == 0);
+                    ::core::assert!(<$BitFlags as $crate::Flags>::from_bits(bits).is_some() == (unknown == 0));
+                    ::core::assert!(<$BitFlags as ::core::convert::TryFrom<$T>>::try_from(bits).is_ok() == (unknown == 0));
+                }
+            }
+        }
+    };
+    // option: `quickcheck`
+    (@option quickcheck; $($info:tt)*) => {
+        $crate::__tiny_bit_flags_quickcheck! { $($info)* }
+    };
+    // option: `module` or `module = name`
+    (@option module; [$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        $crate::__paste! {
+            $crate::__tiny_bit_flags! {
+                @module [<$BitFlags:snake>]; [$($opt)*] $vis struct $BitFlags: $T { $($body)* }
+            }
+        }
+    };
+    (@option module = $name:ident; $($info:tt)*) => {
+        $crate::__tiny_bit_flags! { @module $name; $($info)* }
+    };
+    // option: `debug_checks`, used by `@debug_checks`
+    (@option debug_checks; $($info:tt)*) => {};
+    // option: `display_case = "..."`, used by `@impl`
+    (@option display_case = "lower"; $($info:tt)*) => {};
+    (@option display_case = "kebab"; $($info:tt)*) => {};
+    (@option display_case = $case:tt; $($info:tt)*) => {
+        ::core::compile_error!(::core::concat!("unknown display_case: ", ::core::stringify!($case), ", expected \"lower\" or \"kebab\""));
+    };
+    // option: `display_empty = "..."`, used by `@impl`
+    (@option display_empty = $empty:literal; $($info:tt)*) => {};
+    // option: `impl_attrs(...)`, used by `@impl_attrs`
+    (@option impl_attrs($($_:tt)*); $($info:tt)*) => {};
+    // option: `impl_vis(...)`, used by `@impl_vis`, and `__struct_vis` by it
+    (@option impl_vis($($_:tt)*); $($info:tt)*) => {};
+    (@option __struct_vis($($_:tt)*); $($info:tt)*) => {};
+    // option: `inline` or `inline(...)`, used by `@fn_attrs`
+    (@option inline; $($info:tt)*) => {};
+    (@option inline($($_:tt)*); $($info:tt)*) => {};
+    // option: `__inner = name`, the inner type, used by `also_as`
+    (@option __inner = $Inner:ident; $($info:tt)*) => {};
+    // option: `bit_consts`, used by `@flag_items` and `@module_items`
+    (@option bit_consts; $($info:tt)*) => {};
+    // option: `msb0`, used by `@impl`
+    (@option msb0; $($info:tt)*) => {};
+    // option: `must_use`, used by `@fn_attrs`
+    (@option must_use; $($info:tt)*) => {};
+    // option: `methods(...)`, used by `@impl`
+    (@option methods($($family:ident),* $(,)?); $($info:tt)*) => {
+        $($crate::__tiny_bit_flags! { @method_family $family })*
+    };
+
+    (@option $key:ident $($_:tt)*) => {
+        ::core::compile_error!(::core::concat!("unknown tiny_bit_flags option: `", ::core::stringify!($key), "`"));
+    };
+
+    // per-flag methods of the family, unless left out by `methods(...)`
+    (@methods $family:ident [] $($item:tt)*) => { $($item)* };
+    (@methods $family:ident [methods($($list:tt)*) $($_:tt)*] $($item:tt)*) => {
+        $crate::__tiny_bit_flags! { @methods_in $family [$($list)*] $($item)* }
+    };
+    (@methods $family:ident [$_:tt $($rest:tt)*] $($item:tt)*) => {
+        $crate::__tiny_bit_flags! { @methods $family [$($rest)*] $($item)* }
+    };
+    (@methods_in is [is $($_:tt)*] $($item:tt)*) => { $($item)* };
+    (@methods_in is_in [is_in $($_:tt)*] $($item:tt)*) => { $($item)* };
+    (@methods_in set [set $($_:tt)*] $($item:tt)*) => { $($item)* };
+    (@methods_in clear [clear $($_:tt)*] $($item:tt)*) => { $($item)* };
+    (@methods_in $family:ident [] $($item:tt)*) => {};
+    (@methods_in $family:ident [$_:tt $($rest:tt)*] $($item:tt)*) => {
+        $crate::__tiny_bit_flags! { @methods_in $family [$($rest)*] $($item)* }
+    };
+    (@method_family is) => {};
+    (@method_family is_in) => {};
+    (@method_family set) => {};
+    (@method_family clear) => {};
+    (@method_family $family:ident) => {
+        ::core::compile_error!(::core::concat!("unknown method family: `", ::core::stringify!($family), "`"));
+    };
+
+    // the flag constants in a module, for `module`
+    (
+        @module $name:ident; $opts:tt
+        $vis:vis struct $BitFlags:ident: $T:ty {
+            $(
+                $(#[$inner:ident $($args:tt)*])*
+                $(pub $(($($fvis:tt)*))?)? const $Flag:tt = $value:expr;
+            )*
+        }
+    ) => {
+        #[doc = ::core::concat!("Flag constants of [`", ::core::stringify!($BitFlags), "`].")]
+        $vis mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            // the values as associated constants, so a value can refer to
+            // another flag by `Self::`, as without `module`
+            #[doc(hidden)]
+            pub(super) trait __Values {
+                $(const $Flag: $T;)*
+            }
+            #[doc(hidden)]
+            pub(super) struct __Consts;
+            impl __Values for __Consts {
+                $(const $Flag: $T = $value;)*
+            }
+
+            $(
+                $crate::__tiny_bit_flags! {
+                    @module_vis [$(pub $(($($fvis)*))?)?] $opts $BitFlags
+                    $(#[$inner $($args)*])*
+                    const $Flag: $T = <__Consts as __Values>::$Flag;
+                }
+            )*
+        }
+
+        // and of the struct, for the values in its impl blocks
+        use $name::__Values as _;
+        impl $name::__Values for $BitFlags {
+            $(const $Flag: $T = <$name::__Consts as $name::__Values>::$Flag;)*
+        }
+    };
+    // the flag's visibility seen from inside the module
+    (@module_vis [] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @module_items [pub] $($rest)* }
+    };
+    (@module_vis [pub] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @module_items [pub] $($rest)* }
+    };
+    (@module_vis [pub(crate)] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @module_items [pub(crate)] $($rest)* }
+    };
+    (@module_vis [pub(self)] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @module_items [pub(super)] $($rest)* }
+    };
+    (@module_vis [pub(super)] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @module_items [pub(in super::super)] $($rest)* }
+    };
+    (@module_vis [pub(in crate $($path:tt)*)] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @module_items [pub(in crate $($path)*)] $($rest)* }
+    };
+    (@module_vis [pub(in self $($path:tt)*)] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @module_items [pub(in super $($path)*)] $($rest)* }
+    };
+    (@module_vis [pub(in super $($path:tt)*)] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @module_items [pub(in super::super $($path)*)] $($rest)* }
+    };
+    (
+        @module_items [$vis:vis] $opts:tt $BitFlags:ident
+        $(#[$inner:meta])* const $Flag:tt: $T:ty = $value:expr;
+    ) => {
+        $crate::__tiny_bit_flags! { @if typed_consts $opts {
+            $(#[$inner])*
+            $vis const $Flag: $BitFlags = $BitFlags($value);
+        } {
+            $(#[$inner])*
+            $vis const $Flag: $T = $value;
+        } }
+        $crate::__tiny_bit_flags! { @if bit_consts $opts {
+            $crate::__paste! {
+                $vis const [<$Flag _BIT>]: u32 = {
+                    let value: $T = $value;
+                    $crate::__tiny_bit_flags!(@if msb0 $opts {
+                        value.leading_zeros()
+                    } {
+                        value.trailing_zeros()
+                    })
+                };
+            }
+        } {} }
+    };
+
+    // the invariants of the value after a change, with `debug_checks`
+    (@debug_checks $opts:tt $flags:tt) => {
+        $crate::__tiny_bit_flags! { @if debug_checks $opts {
+            ::core::debug_assert!(
+                $flags.0 & $crate::__tiny_bit_flags!(@get reserved $opts { 0 }) == 0,
+                "reserved bits are set",
+            );
+            $crate::__tiny_bit_flags! { @if width $opts {
+                ::core::debug_assert!($flags.0 & !Self::WIDTH_MASK == 0, "bits out of the width are set");
+            } {} }
+        } {} }
+    };
+
+    // the doc comment in attributes, as a string literal; the lines are
+    // joined by the space that leads each line of `///`
+    (@doc [$($doc:tt)*]) => { ::core::concat!($($doc),*) };
+    (@doc [$($doc:tt)*] #[doc = $line:literal] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags!(@doc [$($doc)* $line] $($rest)*)
+    };
+    (@doc [$($doc:tt)*] #[$($_:tt)*] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags!(@doc [$($doc)*] $($rest)*)
+    };
+
+    // the attributes of generated functions by `inline` and `must_use`, for
+    // `@$then`
+    (@fn_attrs [] $inline:tt $must_use:tt @$then:ident $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @$then $inline $must_use $($rest)* }
+    };
+    (@fn_attrs [inline($($i:tt)*) $($more:tt)*] [] $must_use:tt $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @fn_attrs [$($more)*] [#[inline($($i)*)]] $must_use $($rest)* }
+    };
+    (@fn_attrs [inline $($more:tt)*] [] $must_use:tt $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @fn_attrs [$($more)*] [#[inline]] $must_use $($rest)* }
+    };
+    (@fn_attrs [must_use $($more:tt)*] $inline:tt [] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @fn_attrs [$($more)*] $inline [#[must_use]] $($rest)* }
+    };
+    (@fn_attrs [$_:tt $($more:tt)*] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @fn_attrs [$($more)*] $($rest)* }
+    };
+
+    // the attributes of the generated impl blocks by `impl_attrs(...)`, for
+    // `@$then`, after the ones by `@fn_attrs`
+    (@impl_attrs $inline:tt $must_use:tt [] $attrs:tt @$then:ident $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @$then $inline $must_use $attrs $($rest)* }
+    };
+    (
+        @impl_attrs $inline:tt $must_use:tt [impl_attrs($($a:meta),* $(,)?) $($more:tt)*]
+        [$($attr:tt)*] $($rest:tt)*
+    ) => {
+        $crate::__tiny_bit_flags! { @impl_attrs $inline $must_use [$($more)*] [$($attr)* $(#[$a])*] $($rest)* }
+    };
+    (@impl_attrs $inline:tt $must_use:tt [$_:tt $($more:tt)*] $($rest:tt)*) => {
+        $crate::__tiny_bit_flags! { @impl_attrs $inline $must_use [$($more)*] $($rest)* }
+    };
+
+    // the tokens of the first block if an option is given, or of the second
+    (@if $key:ident [] $then:tt { $($else:tt)* }) => { $($else)* };
+    (@if module [module $($_:tt)*] { $($then:tt)* } $else:tt) => { $($then)* };
+    (@if serde_lenient [serde_lenient $($_:tt)*] { $($then:tt)* } $else:tt) => { $($then)* };
+    (@if serde_flexible [serde_flexible $($_:tt)*] { $($then:tt)* } $else:tt) => { $($then)* };
+    (@if serde_strict [serde_strict $($_:tt)*] { $($then:tt)* } $else:tt) => { $($then)* };
+    (@if typed_consts [typed_consts $($_:tt)*] { $($then:tt)* } $else:tt) => { $($then)* };
+    (@if no_derives [no_derives $($_:tt)*] { $($then:tt)* } $else:tt) => { $($then)* };
+    (@if msb0 [msb0 $($_:tt)*] { $($then:tt)* } $else:tt) => { $($then)* };
+    (@if bit_consts [bit_consts $($_:tt)*] { $($then:tt)* } $else:tt) => { $($then)* };
+    (@if rusqlite_strict [rusqlite_strict $($_:tt)*] { $($then:tt)* } $else:tt) => { $($then)* };
+    (@if width [width $($_:tt)*] { $($then:tt)* } $else:tt) => { $($then)* };
+    (@if debug_checks [debug_checks $($_:tt)*] { $($then:tt)* } $else:tt) => { $($then)* };
+    (@if $key:ident [$_:tt $($rest:tt)*] $then:tt $else:tt) => {
+        $crate::__tiny_bit_flags! { @if $key [$($rest)*] $then $else }
+    };
+
+    // the value of an option if given, or the tokens of the block
+    (@get $key:ident [] { $($default:tt)* }) => { $($default)* };
+    (@get display_empty [display_empty = $value:tt $($_:tt)*] $default:tt) => { $value };
+    (@get display_case [display_case = $value:tt $($_:tt)*] $default:tt) => { $value };
+    (@get reserved [reserved = $value:tt $($_:tt)*] $default:tt) => { $value };
+    (@get width [width = $value:tt $($_:tt)*] $default:tt) => { $value };
+    (@get $key:ident [$_:tt $($rest:tt)*] $default:tt) => {
+        $crate::__tiny_bit_flags! { @get $key [$($rest)*] $default }
+    };
+
+    // whether an option is given, as a `bool` literal
+    (@has $key:ident $opts:tt) => {
+        $crate::__tiny_bit_flags!(@if $key $opts { true } { false })
+    };
+}
+
+/// Error of parsing a bit-flags struct generated by [`tiny_bit_flags!`]
+/// from a string, with the span of the invalid token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    offset: usize,
+    len: usize,
+    #[cfg(feature = "alloc")]
+    token: __alloc::string::String,
+    names: &'static [&'static str],
+}
+
+impl ParseError {
+    // the token of `len` bytes at `offset` of the input, as found by the
+    // caller
+    #[doc(hidden)]
+    pub fn __at(input: &str, offset: usize, len: usize, names: &'static [&'static str]) -> Self {
+        #[cfg(not(feature = "alloc"))]
+        let _ = input;
+        ParseError {
+            offset,
+            len,
+            #[cfg(feature = "alloc")]
+            token: input.get(offset..offset + len).unwrap_or("").into(),
+            names,
+        }
+    }
+
+    /// The invalid token, whole; empty for a missing one.
+    #[cfg(feature = "alloc")]
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// The byte offset of the invalid token in the string.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The byte range of the invalid token in the string, e.g. to get the
+    /// token without the `alloc` feature.
+    pub fn span(&self) -> core::ops::Range<usize> {
+        self.offset..self.offset + self.len
+    }
+
+    /// The names of the declared flags.
+    pub fn valid_names(&self) -> &'static [&'static str] {
+        self.names
+    }
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        #[cfg(feature = "alloc")]
+        write!(
+            f,
+            "invalid flag `{}` at byte {}, expected a number or one of: ",
+            self.token, self.offset
+        )?;
+        #[cfg(not(feature = "alloc"))]
+        write!(
+            f,
+            "invalid flag at bytes {}..{}, expected a number or one of: ",
+            self.offset,
+            self.offset + self.len
+        )?;
+        for (i, name) in self.names.iter().enumerate() {
+            f.write_str(if i == 0 { "" } else { ", " })?;
+            f.write_str(name)?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+/// Error of building a bit-flags struct generated by [`tiny_bit_flags!`]
+/// from pairs of names and values, with the index of the pair of an invalid
+/// name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PairError {
+    index: usize,
+    error: ParseError,
+}
+
+impl PairError {
+    #[doc(hidden)]
+    pub fn __at(index: usize, name: &str, names: &'static [&'static str]) -> Self {
+        PairError {
+            index,
+            error: ParseError::__at(name, 0, name.len(), names),
+        }
+    }
+
+    /// The index of the pair of the invalid name.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The error of the invalid name, with the span in the name.
+    pub fn error(&self) -> &ParseError {
+        &self.error
+    }
+}
+
+impl core::fmt::Display for PairError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "pair {}: {}", self.index, self.error)
+    }
+}
+
+impl core::error::Error for PairError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Error of converting an integer to a bit-flags struct generated by
+/// [`tiny_bit_flags!`], for bits not of any declared flag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownBitsError(#[doc(hidden)] pub ()); // `pub` for generated code
+
+impl core::fmt::Display for UnknownBitsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("unknown bits in flags")
+    }
+}
+
+impl core::error::Error for UnknownBitsError {}
+
+/// Error of building a bit-flags struct generated by [`tiny_bit_flags!`]
+/// from names, for a name not of any declared flag.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownNameError(#[doc(hidden)] pub __alloc::string::String); // `pub` for generated code
+
+#[cfg(feature = "alloc")]
+impl UnknownNameError {
+    /// The unknown name.
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for UnknownNameError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "unknown flag name: `{}`", self.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for UnknownNameError {}
+
+// Helpers of `Display` and `FromStr`: a flag name in the case of the
+// `display_case` option, or as it is for an empty `case`.
+#[doc(hidden)]
+pub fn __write_name(f: &mut dyn core::fmt::Write, name: &str, case: &str) -> core::fmt::Result {
+    if case.is_empty() {
+        return f.write_str(name);
+    }
+    for c in name.chars() {
+        let c = if case == "kebab" && c == '_' {
+            '-'
+        } else {
+            c.to_ascii_lowercase()
+        };
+        f.write_char(c)?;
+    }
+    Ok(())
+}
+// Helper of `Display`: write by `write`, padded to the width of the
+// formatter with its fill and alignment, left by default as for strings.
+#[doc(hidden)]
+pub fn __write_padded(
+    f: &mut core::fmt::Formatter,
+    write: impl Fn(&mut dyn core::fmt::Write) -> core::fmt::Result,
+) -> core::fmt::Result {
+    let Some(width) = f.width() else {
+        return write(f);
+    };
+
+    struct Len(usize);
+    impl core::fmt::Write for Len {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            self.0 += s.chars().count();
+            Ok(())
+        }
+    }
+    let mut len = Len(0);
+    write(&mut len)?;
+
+    let pad = width.saturating_sub(len.0);
+    let (before, after) = match f.align() {
+        Some(core::fmt::Alignment::Right) => (pad, 0),
+        Some(core::fmt::Alignment::Center) => (pad / 2, pad - pad / 2),
+        _ => (0, pad),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        core::fmt::Write::write_char(f, fill)?;
+    }
+    write(f)?;
+    for _ in 0..after {
+        core::fmt::Write::write_char(f, fill)?;
+    }
+    Ok(())
+}
+
+#[doc(hidden)]
+pub fn __name_matches(name: &str, token: &str, case: &str) -> bool {
+    !case.is_empty()
+        && name.len() == token.len()
+        && name.chars().zip(token.chars()).all(|(n, t)| {
+            let n = if case == "kebab" && n == '_' {
+                '-'
+            } else {
+                n.to_ascii_lowercase()
+            };
+            n == t
+        })
+}
+
+// Helper of `FromStr`: the tokens between the separators, trimmed, with
+// their byte offsets in the string.
+#[doc(hidden)]
+pub fn __split_trimmed(s: &str, sep: char) -> impl Iterator<Item = (usize, &str)> {
+    let mut start = 0;
+    s.split(sep).map(move |piece| {
+        let offset = start + (piece.len() - piece.trim_start().len());
+        start += piece.len() + sep.len_utf8();
+        (offset, piece.trim())
+    })
+}
+
+// Helper of `FromStr`: parse a hexadecimal, octal or binary token as raw
+// bits of an integer of `width` bits, so that `0x80000000` is accepted for
+// `i32` too. `None` if the token has none of these prefixes, and `Some(None)`
+// if it's invalid.
+#[doc(hidden)]
+pub fn __parse_raw_bits(token: &str, width: u32) -> Option<Option<u128>> {
+    let prefixes = [
+        ("0x", 16),
+        ("0X", 16),
+        ("0o", 8),
+        ("0O", 8),
+        ("0b", 2),
+        ("0B", 2),
+    ];
+    for (prefix, radix) in prefixes {
+        if let Some(digits) = token.strip_prefix(prefix) {
+            let raw = u128::from_str_radix(digits, radix)
+                .ok()
+                .filter(|raw| raw.checked_shr(width).unwrap_or(0) == 0);
+            return Some(raw);
+        }
+    }
+    None
+}
+
+/// Common interface of the bit-flags structs generated by [`tiny_bit_flags!`],
+/// for generic programming.
+///
+/// Example:
+///
+/// ```rust
+/// use tiny_bit_flags::Flags;
+///
+/// tiny_bit_flags::tiny_bit_flags! {
+///     struct PrimFlags: u32 {
+///         const WRITABLE   = 0b00000001;
+///         const EXECUTABLE = 0b00000010;
+///     }
+/// }
+///
+/// fn missing<F: Flags>(have: &F, want: &F) -> Vec<&'static str> {
+///     F::FLAG_NAMES
+///         .iter()
+///         .zip(F::FLAG_VALUES)
+///         .filter(|&(_, &value)| {
+///             let flag = || F::from_bits(value).unwrap();
+///             want.contains(flag()) && !have.contains(flag())
+///         })
+///         .map(|(&name, _)| name)
+///         .collect()
+/// }
+///
+/// let have = PrimFlags(PrimFlags::WRITABLE);
+/// assert_eq!(missing(&have, &PrimFlags::all()), ["EXECUTABLE"]);
+/// assert_eq!(PrimFlags::all().bits(), 0b11);
+/// assert!(PrimFlags::from_bits(0b100).is_none());
+/// ```
+pub trait Flags: Sized {
+    /// The inner type.
+    type Bits: Copy + 'static;
+
+    /// Names of flags, in declaration order.
+    const FLAG_NAMES: &'static [&'static str];
+
+    /// Values of flags, in declaration order.
+    const FLAG_VALUES: &'static [Self::Bits];
+
+    /// No flag is set.
+    fn empty() -> Self;
+
+    /// All declared flags are set.
+    fn all() -> Self;
+
+    /// The inner value.
+    fn bits(&self) -> Self::Bits;
+
+    /// Construct from the inner value, or `None` if any bit is not of a
+    /// declared flag.
+    fn from_bits(bits: Self::Bits) -> Option<Self>;
+
+    /// Whether all flags set in `other` are also set in `self`.
+    fn contains(&self, other: Self) -> bool;
+}
+
+/// Invoke a callback macro once for each flag declared by
+/// [`tiny_bit_flags!`], as `callback! { Struct, FLAG, value }`, at compile
+/// time.
+///
+/// See module-level document for details.
+///
+/// Example:
+///
+/// ```rust
+/// tiny_bit_flags::tiny_bit_flags! {
+///     struct PrimFlags: u32 {
+///         const WRITABLE   = 0b00000001;
+///         const EXECUTABLE = 0b00000010;
+///     }
+/// }
+///
+/// macro_rules! print_flag {
+///     ($BitFlags:ident, $Flag:ident, $value:expr) => {
+///         println!("{}::{} = {:#b}", stringify!($BitFlags), stringify!($Flag), $value);
+///     };
+/// }
+/// tiny_bit_flags::for_each_flag!(PrimFlags, print_flag);
+/// ```
+///
+#[macro_export]
+macro_rules! for_each_flag {
+    ($BitFlags:ident, $callback:ident) => {
+        $crate::__paste! { [<__tiny_bit_flags_each_ $BitFlags>]! { $callback } }
+    };
+}
+
+/// A value of a struct generated by [`tiny_bit_flags!`], with the flags
+/// of the names set.
+///
+/// See module-level document for details.
+///
+/// Example:
+///
+/// ```rust
+/// use tiny_bit_flags::flags;
+///
+/// tiny_bit_flags::tiny_bit_flags! {
+///     struct PrimFlags: u32 {
+///         const WRITABLE   = 0b00000001;
+///         const EXECUTABLE = 0b00000010;
+///     }
+/// }
+///
+/// static DEFAULT: PrimFlags = flags!(PrimFlags: WRITABLE | EXECUTABLE);
+/// assert_eq!(DEFAULT.0, 0b11);
+/// assert_eq!(flags!(PrimFlags: EXECUTABLE).0, PrimFlags::EXECUTABLE);
+/// assert_eq!(flags!(PrimFlags:).0, 0);
+/// ```
+///
+/// An unknown name is a compile error:
+///
+/// ```rust,compile_fail
+/// # tiny_bit_flags::tiny_bit_flags! {
+/// #     struct PrimFlags: u32 {
+/// #         const WRITABLE   = 0b00000001;
+/// #     }
+/// # }
+/// let f = tiny_bit_flags::flags!(PrimFlags: WRITEABLE);
+/// ```
+///
+#[macro_export]
+macro_rules! flags {
+    ($BitFlags:ty: $($Flag:ident)|* $(|)?) => {
+        const {
+            <$BitFlags>::union_all(&[$(
+                match <$BitFlags>::from_name(::core::stringify!($Flag)) {
+                    ::core::option::Option::Some(flag) => flag,
+                    ::core::option::Option::None => ::core::panic!(::core::concat!("unknown flag: `", ::core::stringify!($Flag), "`")),
+                }
+            ),*])
+        }
+    };
+}
+
+// Helpers of `from_name()`: a hash table of the flag names, with linear
+// probing, built at compile time. Each slot holds the index of the name
+// plus 1, or 0 if empty.
+
+#[doc(hidden)]
+pub const fn __name_table_len(num_flags: usize) -> usize {
+    // keep at least half of the slots empty
+    (num_flags * 2).next_power_of_two()
+}
+
+#[doc(hidden)]
+pub const fn __name_table<const N: usize>(names: &[&str]) -> [usize; N] {
+    let mut table = [0; N];
+    let mut i = 0;
+    while i < names.len() {
+        let mut slot = name_hash(names[i]) & (N - 1);
+        while table[slot] != 0 {
+            slot = (slot + 1) & (N - 1);
+        }
+        table[slot] = i + 1;
+        i += 1;
+    }
+    table
+}
+
+#[doc(hidden)]
+pub const fn __name_lookup(table: &[usize], names: &[&str], name: &str) -> Option<usize> {
+    let mask = table.len() - 1;
+    let mut slot = name_hash(name) & mask;
+    loop {
+        match table[slot] {
+            0 => return None,
+            i if str_eq(names[i - 1], name) => return Some(i - 1),
+            _ => slot = (slot + 1) & mask,
+        }
+    }
+}
+
+// FNV-1a
+const fn name_hash(name: &str) -> usize {
+    let bytes = name.as_bytes();
+    let mut hash: u32 = 0x811c9dc5;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u32;
+        hash = hash.wrapping_mul(0x01000193);
+        i += 1;
+    }
+    hash as usize
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+// Helper of the `bit_band` option: the address of the word in the alias
+// region of Cortex-M3/M4 whose bit 0 is the bit `n` of the register, which
+// must be in the bit-band region of SRAM or of peripherals.
+
+#[doc(hidden)]
+pub const fn __bit_band(register: usize, n: u32) -> usize {
+    let offset = register & 0x000f_ffff;
+    let region = register - offset;
+    assert!(
+        region == 0x2000_0000 || region == 0x4000_0000,
+        "register out of the bit-band regions"
+    );
+    region + 0x0200_0000 + offset * 32 + n as usize * 4
+}
+
+// The atomic type of each integer type, for the `atomic` option.
+
+#[doc(hidden)]
+pub trait __Atomic {
+    type Atomic;
+}
+
+macro_rules! impl_atomic {
+    ($($width:literal: $($T:ty => $Atomic:ident),*;)*) => {
+        $($(
+            #[cfg(target_has_atomic = $width)]
+            impl __Atomic for $T {
+                type Atomic = core::sync::atomic::$Atomic;
+            }
+        )*)*
+    };
+}
+
+impl_atomic! {
+    "8": u8 => AtomicU8, i8 => AtomicI8;
+    "16": u16 => AtomicU16, i16 => AtomicI16;
+    "32": u32 => AtomicU32, i32 => AtomicI32;
+    "64": u64 => AtomicU64, i64 => AtomicI64;
+    "ptr": usize => AtomicUsize, isize => AtomicIsize;
+}
+
+#[doc(hidden)]
+pub use paste::paste as __paste;
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde as __serde;
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_serde {
+    // a declared flag, deserialized from its name, or `None` for an unknown
+    // name with `serde_lenient`
+    (@flag [$($opt:tt)*] $BitFlags:ident: $T:ty) => {
+        struct __Flag(::core::option::Option<$T>);
+
+        impl<'de> $crate::__serde::Deserialize<'de> for __Flag {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__serde::Deserializer<'de>,
+            {
+                struct __Visitor;
+
+                impl $crate::__serde::de::Visitor<'_> for __Visitor {
+                    type Value = __Flag;
+
+                    fn expecting(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        f.write_str("a flag name")
+                    }
+
+                    fn visit_str<E>(self, name: &str) -> ::core::result::Result<__Flag, E>
+                    where
+                        E: $crate::__serde::de::Error,
+                    {
+                        match $BitFlags::from_name(name) {
+                            ::core::option::Option::Some(flag) => ::core::result::Result::Ok(__Flag(::core::option::Option::Some(flag.0))),
+                            ::core::option::Option::None if $crate::__tiny_bit_flags!(@has serde_lenient [$($opt)*]) => {
+                                ::core::result::Result::Ok(__Flag(::core::option::Option::None))
+                            }
+                            ::core::option::Option::None => ::core::result::Result::Err(E::unknown_variant(name, $BitFlags::FLAG_NAMES)),
+                        }
+                    }
+                }
+
+                deserializer.deserialize_str(__Visitor)
+            }
+        }
+    };
+
+    // the backing integer as little-endian bytes, so always of the same width
+    ("fixed"; [$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        impl $crate::__serde::Serialize for $BitFlags {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__serde::Serializer,
+            {
+                $crate::__serde::Serialize::serialize(&self.0.to_le_bytes(), serializer)
+            }
+        }
+
+        // the bytes can't be told from a list of flag names, and the
+        // binary formats it's for are not self-describing anyway
+        $crate::__tiny_bit_flags! { @if serde_flexible [$($opt)*] {
+            ::core::compile_error!("the `serde_flexible` option conflicts with `serde = \"fixed\"`");
+        } {
+            impl<'de> $crate::__serde::Deserialize<'de> for $BitFlags {
+                fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+                where
+                    D: $crate::__serde::Deserializer<'de>,
+                {
+                    let bytes: [u8; ::core::mem::size_of::<$T>()] =
+                        $crate::__serde::Deserialize::deserialize(deserializer)?;
+                    $crate::__tiny_bit_flags_serde!(@checked [$($opt)*] $BitFlags(<$T>::from_le_bytes(bytes)))
+                }
+            }
+        } }
+    };
+
+    // a list of the names of the set flags
+    ("list"; [$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        impl $crate::__serde::Serialize for $BitFlags {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__serde::Serializer,
+            {
+                use ::core::iter::Iterator as _;
+                use $crate::__serde::ser::{Error as _, SerializeSeq};
+
+                // the flags as written by `Display`, and the unknown bits
+                let names = || {
+                    let mut rest = self.0;
+                    let names = Self::FLAG_NAMES.iter().zip(Self::FLAG_VALUES).filter(move |&(_, &value)| {
+                        let written = value != 0 && self.0 & value == value && rest & value != 0;
+                        if written {
+                            rest &= !value;
+                        }
+                        written
+                    });
+                    names.map(|(&name, _)| name)
+                };
+                let unknown = self.0 & !Self::FLAG_VALUES.iter().fold(0, |all, &value| all | value);
+                if unknown != 0 {
+                    return ::core::result::Result::Err(S::Error::custom(::core::format_args!(
+                        "unknown bits {:#x} of `{}` have no names",
+                        unknown,
+                        ::core::stringify!($BitFlags),
+                    )));
+                }
+                let mut seq = serializer.serialize_seq(::core::option::Option::Some(names().count()))?;
+                for name in names() {
+                    seq.serialize_element(name)?;
+                }
+                seq.end()
+            }
+        }
+
+        $crate::__tiny_bit_flags! { @if serde_flexible [$($opt)*] {
+            $crate::__tiny_bit_flags_serde! { @flexible [$($opt)*] $BitFlags: $T }
+        } {
+            impl<'de> $crate::__serde::Deserialize<'de> for $BitFlags {
+                fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+                where
+                    D: $crate::__serde::Deserializer<'de>,
+                {
+                    use $crate::__serde::de;
+
+                    $crate::__tiny_bit_flags_serde! { @flag [$($opt)*] $BitFlags: $T }
+
+                    struct __Visitor;
+
+                    impl<'de> de::Visitor<'de> for __Visitor {
+                        type Value = $BitFlags;
+
+                        fn expecting(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            f.write_str("a list of flag names")
+                        }
+
+                        fn visit_seq<A>(self, mut seq: A) -> ::core::result::Result<$BitFlags, A::Error>
+                        where
+                            A: de::SeqAccess<'de>,
+                        {
+                            let mut flags = $BitFlags(0);
+                            while let ::core::option::Option::Some(__Flag(value)) = seq.next_element()? {
+                                flags.0 |= value.unwrap_or(0);
+                            }
+                            ::core::result::Result::Ok(flags)
+                        }
+                    }
+
+                    deserializer.deserialize_seq(__Visitor)
+                }
+            }
+        } }
+    };
+
+    // a map from each flag name to whether it's set
+    ("map"; [$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        impl $crate::__serde::Serialize for $BitFlags {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__serde::Serializer,
+            {
+                use ::core::iter::Iterator as _;
+                use $crate::__serde::ser::{Error as _, SerializeMap};
+                let unknown = self.0 & !Self::FLAG_VALUES.iter().fold(0, |all, &value| all | value);
+                if unknown != 0 {
+                    return ::core::result::Result::Err(S::Error::custom(::core::format_args!(
+                        "unknown bits {:#x} of `{}` have no names",
+                        unknown,
+                        ::core::stringify!($BitFlags),
+                    )));
+                }
+                let mut map = serializer.serialize_map(::core::option::Option::Some(Self::NUM_FLAGS))?;
+                for (name, &value) in Self::FLAG_NAMES.iter().zip(Self::FLAG_VALUES) {
+                    map.serialize_entry(name, &(self.0 & value == value))?;
+                }
+                map.end()
+            }
+        }
+
+        $crate::__tiny_bit_flags! { @if serde_flexible [$($opt)*] {
+            $crate::__tiny_bit_flags_serde! { @flexible [$($opt)*] $BitFlags: $T }
+        } {
+            impl<'de> $crate::__serde::Deserialize<'de> for $BitFlags {
+                fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+                where
+                    D: $crate::__serde::Deserializer<'de>,
+                {
+                    use $crate::__serde::de;
+
+                    $crate::__tiny_bit_flags_serde! { @flag [$($opt)*] $BitFlags: $T }
+
+                    struct __Visitor;
+
+                    impl<'de> de::Visitor<'de> for __Visitor {
+                        type Value = $BitFlags;
+
+                        fn expecting(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            f.write_str("a map from flag names to booleans")
+                        }
+
+                        fn visit_map<A>(self, mut map: A) -> ::core::result::Result<$BitFlags, A::Error>
+                        where
+                            A: de::MapAccess<'de>,
+                        {
+                            // missing flags are taken as not set, and so are the
+                            // `false` ones, which then don't clear the bits
+                            // shared with `true` ones, in whatever order
+                            let mut flags = $BitFlags(0);
+                            while let ::core::option::Option::Some(__Flag(value)) = map.next_key()? {
+                                match value {
+                                    ::core::option::Option::Some(value) if map.next_value()? => flags.0 |= value,
+                                    ::core::option::Option::Some(_) => {}
+                                    ::core::option::Option::None => {
+                                        map.next_value::<de::IgnoredAny>()?;
+                                    }
+                                }
+                            }
+                            ::core::result::Result::Ok(flags)
+                        }
+                    }
+
+                    deserializer.deserialize_map(__Visitor)
+                }
+            }
+        } }
+    };
+
+    // names or numbers as by `Display`, but separated by `,`, e.g. for
+    // query strings
+    ("comma"; [$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        impl $crate::__serde::Serialize for $BitFlags {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__serde::Serializer,
+            {
+                struct __Comma<'a>(&'a $BitFlags);
+
+                impl ::core::fmt::Display for __Comma<'_> {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        self.0.__write_separated(f, ",")
+                    }
+                }
+
+                serializer.collect_str(&__Comma(self))
+            }
+        }
+
+        $crate::__tiny_bit_flags! { @if serde_flexible [$($opt)*] {
+            $crate::__tiny_bit_flags_serde! { @flexible [$($opt)*] $BitFlags: $T }
+        } {
+            impl<'de> $crate::__serde::Deserialize<'de> for $BitFlags {
+                fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+                where
+                    D: $crate::__serde::Deserializer<'de>,
+                {
+                    use $crate::__serde::de;
+
+                    struct __Visitor;
+
+                    impl de::Visitor<'_> for __Visitor {
+                        type Value = $BitFlags;
+
+                        fn expecting(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            f.write_str("flag names separated by commas")
+                        }
+
+                        fn visit_str<E: de::Error>(self, s: &str) -> ::core::result::Result<$BitFlags, E> {
+                            match $crate::__tiny_bit_flags_serde!(@comma [$($opt)*] $BitFlags(s)) {
+                                ::core::result::Result::Ok(flags) => $crate::__tiny_bit_flags_serde!(@checked [$($opt)*] $BitFlags(flags.0)),
+                                ::core::result::Result::Err(err) => ::core::result::Result::Err(E::custom(err)),
+                            }
+                        }
+                    }
+
+                    deserializer.deserialize_str(__Visitor)
+                }
+            }
+        } }
+    };
+
+    // the flags of the string separated by `,`, each part as by `FromStr`,
+    // skipping the invalid parts with `serde_lenient`
+    (@comma [$($opt:tt)*] $BitFlags:ident($s:expr)) => {{
+        use ::core::iter::Iterator as _;
+        $s.split(',').try_fold($BitFlags(0), |flags, part| match part.parse::<$BitFlags>() {
+            ::core::result::Result::Ok(part) => ::core::result::Result::Ok($BitFlags(flags.0 | part.0)),
+            ::core::result::Result::Err(_) if $crate::__tiny_bit_flags!(@has serde_lenient [$($opt)*]) => {
+                ::core::result::Result::Ok(flags)
+            }
+            ::core::result::Result::Err(err) => ::core::result::Result::Err(err),
+        })
+    }};
+
+    // the flags of the bits, or an error for unknown bits with `serde_strict`
+    (@checked [$($opt:tt)*] $BitFlags:ident($bits:expr)) => {{
+        use ::core::iter::Iterator as _;
+        let flags = $BitFlags($bits);
+        let unknown = flags.0 & !$BitFlags::FLAG_VALUES.iter().fold(0, |all, &value| all | value);
+        if $crate::__tiny_bit_flags!(@has serde_strict [$($opt)*]) && unknown != 0 {
+            ::core::result::Result::Err($crate::__serde::de::Error::custom(::core::format_args!(
+                "unknown bits {:#x} of `{}`",
+                unknown,
+                ::core::stringify!($BitFlags),
+            )))
+        } else {
+            ::core::result::Result::Ok(flags)
+        }
+    }};
+
+    // any of the integer, a string as by `FromStr`, a list of flag names,
+    // and a map as by "map", for `serde_flexible`
+    (@flexible [$($opt:tt)*] $BitFlags:ident: $T:ty) => {
+        impl<'de> $crate::__serde::Deserialize<'de> for $BitFlags {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__serde::Deserializer<'de>,
+            {
+                use $crate::__serde::de;
+
+                $crate::__tiny_bit_flags_serde! { @flag [$($opt)*] $BitFlags: $T }
+
+                struct __Visitor;
+
+                impl<'de> de::Visitor<'de> for __Visitor {
+                    type Value = $BitFlags;
+
+                    fn expecting(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        f.write_str("an integer, a string, a list of flag names or a map to booleans")
+                    }
 
-/// Generate bit-flags struct and methods.
-///
-/// See module-level document for details.
-///
-/// Example:
-///
-/// ```rust
-/// tiny_bit_flags::tiny_bit_flags! {
-///     struct PrimFlags: u32 {
-///         const WRITABLE   = 0b00000001;
-///         const EXECUTABLE = 0b00000010;
-///     }
-/// }
-///
-/// let mut f = PrimFlags(PrimFlags::WRITABLE); // initialize
-/// assert!(f.is_writable()); // check flag
-/// assert!(!f.is_executable());
-///
-/// f.clear_writable(); // clear flag
-/// assert!(!f.is_writable());
-///
-/// f.set_executable(); // set flag
-/// assert!(f.is_executable());
-/// ```
-///
+                    fn visit_u64<E: de::Error>(self, bits: u64) -> ::core::result::Result<$BitFlags, E> {
+                        match ::core::convert::TryFrom::try_from(bits) {
+                            ::core::result::Result::Ok(bits) => $crate::__tiny_bit_flags_serde!(@checked [$($opt)*] $BitFlags(bits)),
+                            ::core::result::Result::Err(_) => ::core::result::Result::Err(E::invalid_value(de::Unexpected::Unsigned(bits), &self)),
+                        }
+                    }
+                    fn visit_i64<E: de::Error>(self, bits: i64) -> ::core::result::Result<$BitFlags, E> {
+                        match ::core::convert::TryFrom::try_from(bits) {
+                            ::core::result::Result::Ok(bits) => $crate::__tiny_bit_flags_serde!(@checked [$($opt)*] $BitFlags(bits)),
+                            ::core::result::Result::Err(_) => ::core::result::Result::Err(E::invalid_value(de::Unexpected::Signed(bits), &self)),
+                        }
+                    }
+
+                    fn visit_str<E: de::Error>(self, s: &str) -> ::core::result::Result<$BitFlags, E> {
+                        match $crate::__tiny_bit_flags_serde!(@comma [$($opt)*] $BitFlags(s)) {
+                            ::core::result::Result::Ok(flags) => $crate::__tiny_bit_flags_serde!(@checked [$($opt)*] $BitFlags(flags.0)),
+                            ::core::result::Result::Err(_) => ::core::result::Result::Err(E::invalid_value(de::Unexpected::Str(s), &self)),
+                        }
+                    }
+
+                    fn visit_seq<A>(self, mut seq: A) -> ::core::result::Result<$BitFlags, A::Error>
+                    where
+                        A: de::SeqAccess<'de>,
+                    {
+                        let mut flags = $BitFlags(0);
+                        while let ::core::option::Option::Some(__Flag(value)) = seq.next_element()? {
+                            flags.0 |= value.unwrap_or(0);
+                        }
+                        ::core::result::Result::Ok(flags)
+                    }
+
+                    fn visit_map<A>(self, mut map: A) -> ::core::result::Result<$BitFlags, A::Error>
+                    where
+                        A: de::MapAccess<'de>,
+                    {
+                        let mut flags = $BitFlags(0);
+                        while let ::core::option::Option::Some(__Flag(value)) = map.next_key()? {
+                            match value {
+                                ::core::option::Option::Some(value) if map.next_value()? => flags.0 |= value,
+                                ::core::option::Option::Some(_) => {}
+                                ::core::option::Option::None => {
+                                    map.next_value::<de::IgnoredAny>()?;
+                                }
+                            }
+                        }
+                        ::core::result::Result::Ok(flags)
+                    }
+                }
+
+                deserializer.deserialize_any(__Visitor)
+            }
+        }
+    };
+
+    ($mode:tt; $($_:tt)*) => {
+        ::core::compile_error!(::core::concat!("unknown serde mode: ", ::core::stringify!($mode)));
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! tiny_bit_flags {
+macro_rules! __tiny_bit_flags_serde {
+    ($($_:tt)*) => {
+        ::core::compile_error!("the `serde` option requires the `serde` feature of tiny-bit-flags");
+    };
+}
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub extern crate alloc as __alloc;
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_alloc {
+    (
+        [$(#[$inline:meta])*] [$(#[$must_use:meta])*] [$(#[$impl_attr:meta])*] $opts:tt
+        $vis:vis struct $BitFlags:ident: $T:ty
+    ) => {
+        $(#[$impl_attr])*
+        impl $BitFlags {
+            // names of the set flags, e.g. for grants as sets of strings
+            $(#[$inline])*
+            $(#[$must_use])*
+            $vis fn to_name_set(&self) -> $crate::__alloc::collections::BTreeSet<&'static str> {
+                use ::core::iter::Iterator as _;
+                self.iter_names().collect()
+            }
+
+            // the flags of the names, as in `FromStr`, or an error with the
+            // first unknown name
+            $(#[$inline])*
+            $vis fn from_names<I>(names: I) -> ::core::result::Result<Self, $crate::UnknownNameError>
+            where
+                I: ::core::iter::IntoIterator,
+                I::Item: ::core::convert::AsRef<str>,
+            {
+                use ::core::iter::Iterator as _;
+                let case = $crate::__tiny_bit_flags!(@get display_case $opts { "" });
+                let mut bits = 0;
+                for name in names {
+                    let name = ::core::convert::AsRef::<str>::as_ref(&name);
+                    let flag = Self::from_name(name).or_else(|| {
+                        Self::FLAG_NAMES
+                            .iter()
+                            .position(|n| $crate::__name_matches(n, name, case))
+                            .map(|i| Self(Self::FLAG_VALUES[i]))
+                    });
+                    match flag {
+                        ::core::option::Option::Some(flag) => bits |= flag.0,
+                        ::core::option::Option::None => return ::core::result::Result::Err($crate::UnknownNameError(::core::convert::From::from(name))),
+                    }
+                }
+                ::core::result::Result::Ok(Self(bits))
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_alloc {
+    ($($_:tt)*) => {};
+}
+
+#[cfg(feature = "bitvec")]
+#[doc(hidden)]
+pub use bitvec as __bitvec;
+
+#[cfg(feature = "bitvec")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_bitvec {
+    ([$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        impl ::core::convert::From<$BitFlags> for $crate::__bitvec::array::BitArray<$T, $crate::__bitvec::order::Lsb0> {
+            fn from(flags: $BitFlags) -> Self {
+                Self::new(flags.0)
+            }
+        }
+
+        impl ::core::convert::From<$crate::__bitvec::array::BitArray<$T, $crate::__bitvec::order::Lsb0>> for $BitFlags {
+            fn from(array: $crate::__bitvec::array::BitArray<$T, $crate::__bitvec::order::Lsb0>) -> Self {
+                Self(array.into_inner())
+            }
+        }
+
+        impl $BitFlags {
+            // view of the inner value as bits
+            $vis fn as_bitslice(&self) -> &$crate::__bitvec::slice::BitSlice<$T, $crate::__bitvec::order::Lsb0> {
+                $crate::__bitvec::view::BitView::view_bits(&self.0)
+            }
+            $vis fn as_mut_bitslice(&mut self) -> &mut $crate::__bitvec::slice::BitSlice<$T, $crate::__bitvec::order::Lsb0> {
+                $crate::__bitvec::view::BitView::view_bits_mut(&mut self.0)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "bitvec"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_bitvec {
+    ($($_:tt)*) => {
+        ::core::compile_error!(
+            "the `bitvec` option requires the `bitvec` feature of tiny-bit-flags"
+        );
+    };
+}
+
+#[cfg(feature = "critical-section")]
+#[doc(hidden)]
+pub use critical_section as __critical_section;
+
+#[cfg(feature = "critical-section")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_critical_section {
+    ([$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        $crate::__paste! {
+            #[doc = ::core::concat!("[`", ::core::stringify!($BitFlags), "`] shared by a critical section, e.g. in a `static` used by both the main code and interrupt handlers.")]
+            $vis struct [<$BitFlags Mutex>](
+                $crate::__critical_section::Mutex<::core::cell::RefCell<$BitFlags>>,
+            );
+
+            impl [<$BitFlags Mutex>] {
+                $vis const fn new(flags: $BitFlags) -> Self {
+                    Self($crate::__critical_section::Mutex::new(::core::cell::RefCell::new(flags)))
+                }
+
+                // access in a critical section, which must not be nested
+                $vis fn with<R>(&self, f: impl ::core::ops::FnOnce(&mut $BitFlags) -> R) -> R {
+                    $crate::__critical_section::with(|cs| f(&mut self.0.borrow_ref_mut(cs)))
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "critical-section"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_critical_section {
+    ($($_:tt)*) => {
+        ::core::compile_error!("the `critical_section` option requires the `critical-section` feature of tiny-bit-flags");
+    };
+}
+
+#[cfg(feature = "modular-bitfield")]
+#[doc(hidden)]
+pub use modular_bitfield as __modular_bitfield;
+
+#[cfg(feature = "modular-bitfield")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_modular_bitfield {
+    ([$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        impl $crate::__modular_bitfield::Specifier for $BitFlags {
+            const BITS: usize = <$T>::BITS as usize;
+            type Bytes = $T;
+            type InOut = Self;
+
+            fn into_bytes(
+                input: Self,
+            ) -> ::core::result::Result<$T, $crate::__modular_bitfield::error::OutOfBounds> {
+                ::core::result::Result::Ok(input.0)
+            }
+            // unknown bits are kept, as everywhere else
+            fn from_bytes(
+                bytes: $T,
+            ) -> ::core::result::Result<
+                Self,
+                $crate::__modular_bitfield::error::InvalidBitPattern<$T>,
+            > {
+                ::core::result::Result::Ok(Self(bytes))
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "modular-bitfield"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_modular_bitfield {
+    ($($_:tt)*) => {
+        ::core::compile_error!("the `modular_bitfield` option requires the `modular-bitfield` feature of tiny-bit-flags");
+    };
+}
+
+#[cfg(feature = "quickcheck")]
+#[doc(hidden)]
+pub use quickcheck as __quickcheck;
+
+#[cfg(feature = "quickcheck")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_quickcheck {
+    ([$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        impl $crate::__quickcheck::Arbitrary for $BitFlags {
+            fn arbitrary(g: &mut $crate::__quickcheck::Gen) -> Self {
+                let mut bits = 0;
+                for &value in Self::FLAG_VALUES {
+                    if <bool as $crate::__quickcheck::Arbitrary>::arbitrary(g) {
+                        bits |= value;
+                    }
+                }
+                Self(bits)
+            }
+
+            fn shrink(&self) -> ::std::boxed::Box<dyn ::core::iter::Iterator<Item = Self>> {
+                use ::core::iter::Iterator as _;
+                // clear one of the set flags
+                let bits = self.0;
+                ::std::boxed::Box::new(
+                    Self::FLAG_VALUES
+                        .iter()
+                        .filter(move |&&value| bits & value == value)
+                        .map(move |&value| Self(bits & !value)),
+                )
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "quickcheck"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_quickcheck {
+    ($($_:tt)*) => {
+        ::core::compile_error!(
+            "the `quickcheck` option requires the `quickcheck` feature of tiny-bit-flags"
+        );
+    };
+}
+
+#[cfg(feature = "ufmt")]
+#[doc(hidden)]
+pub use ufmt as __ufmt;
+
+#[cfg(feature = "ufmt")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_ufmt {
+    ([$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        impl $crate::__ufmt::uDisplay for $BitFlags {
+            // as by `Display`, but without padding
+            fn fmt<W>(&self, f: &mut $crate::__ufmt::Formatter<'_, W>) -> ::core::result::Result<(), W::Error>
+            where
+                W: $crate::__ufmt::uWrite + ?::core::marker::Sized,
+            {
+                use ::core::iter::Iterator as _;
+                if self.0 == 0 {
+                    return f.write_str($crate::__tiny_bit_flags!(@get display_empty [$($opt)*] { "" }));
+                }
+                let case = $crate::__tiny_bit_flags!(@get display_case [$($opt)*] { "" });
+                let mut rest = self.0;
+                let mut sep = "";
+                for (&name, &value) in Self::FLAG_NAMES.iter().zip(Self::FLAG_VALUES) {
+                    if rest == 0 {
+                        break;
+                    }
+                    if self.0 & value == value && rest & value != 0 {
+                        f.write_str(sep)?;
+                        $crate::__uwrite_name(f, name, case)?;
+                        rest &= !value;
+                        sep = " | ";
+                    }
+                }
+                if rest != 0 {
+                    f.write_str(sep)?;
+                    $crate::__uwrite_hex(f, rest as u128 & (u128::MAX >> (128 - <$T>::BITS)))?;
+                }
+                ::core::result::Result::Ok(())
+            }
+        }
+
+        impl $crate::__ufmt::uDebug for $BitFlags {
+            // the names in the struct's, e.g. `PrimFlags(WRITABLE | 0x80)`
+            fn fmt<W>(&self, f: &mut $crate::__ufmt::Formatter<'_, W>) -> ::core::result::Result<(), W::Error>
+            where
+                W: $crate::__ufmt::uWrite + ?::core::marker::Sized,
+            {
+                f.write_str(::core::concat!(::core::stringify!($BitFlags), "("))?;
+                $crate::__ufmt::uDisplay::fmt(self, f)?;
+                f.write_str(")")
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "ufmt"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_ufmt {
+    ($($_:tt)*) => {
+        ::core::compile_error!("the `ufmt` option requires the `ufmt` feature of tiny-bit-flags");
+    };
+}
+
+// Helpers of `uDisplay`: a flag name as by `__write_name()`, and bits in
+// hexadecimal with `0x`, as by `{:#x}`.
+#[cfg(feature = "ufmt")]
+#[doc(hidden)]
+pub fn __uwrite_name<W: ufmt::uWrite + ?Sized>(
+    f: &mut ufmt::Formatter<'_, W>,
+    name: &str,
+    case: &str,
+) -> Result<(), W::Error> {
+    if case.is_empty() {
+        return f.write_str(name);
+    }
+    for c in name.chars() {
+        let c = if case == "kebab" && c == '_' {
+            '-'
+        } else {
+            c.to_ascii_lowercase()
+        };
+        f.write_char(c)?;
+    }
+    Ok(())
+}
+#[cfg(feature = "ufmt")]
+#[doc(hidden)]
+pub fn __uwrite_hex<W: ufmt::uWrite + ?Sized>(
+    f: &mut ufmt::Formatter<'_, W>,
+    bits: u128,
+) -> Result<(), W::Error> {
+    let mut buf = [0; 2 + 32];
+    let mut start = buf.len();
+    let mut rest = bits;
+    loop {
+        start -= 1;
+        buf[start] = b"0123456789abcdef"[(rest & 0xf) as usize];
+        rest >>= 4;
+        if rest == 0 {
+            break;
+        }
+    }
+    start -= 2;
+    buf[start..start + 2].copy_from_slice(b"0x");
+    f.write_str(core::str::from_utf8(&buf[start..]).unwrap_or(""))
+}
+
+#[cfg(feature = "borsh")]
+#[doc(hidden)]
+pub use borsh as __borsh;
+
+#[cfg(feature = "borsh")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_borsh {
+    ([$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        impl $crate::__borsh::BorshSerialize for $BitFlags {
+            // the inner value as little-endian bytes, of a fixed width
+            fn serialize<W: $crate::__borsh::io::Write>(
+                &self,
+                writer: &mut W,
+            ) -> $crate::__borsh::io::Result<()> {
+                $crate::__borsh::BorshSerialize::serialize(&self.0, writer)
+            }
+        }
+
+        impl $crate::__borsh::BorshDeserialize for $BitFlags {
+            // unknown bits are kept
+            fn deserialize_reader<R: $crate::__borsh::io::Read>(
+                reader: &mut R,
+            ) -> $crate::__borsh::io::Result<Self> {
+                <$T as $crate::__borsh::BorshDeserialize>::deserialize_reader(reader).map(Self)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "borsh"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_borsh {
+    ($($_:tt)*) => {
+        ::core::compile_error!("the `borsh` option requires the `borsh` feature of tiny-bit-flags");
+    };
+}
+
+#[cfg(feature = "uniffi")]
+#[doc(hidden)]
+pub use uniffi as __uniffi;
+
+#[cfg(feature = "uniffi")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_uniffi {
     (
-        $(#[$outer:meta])*
+        [$($opt:tt)*]
         $vis:vis struct $BitFlags:ident: $T:ty {
             $(
                 $(#[$inner:ident $($args:tt)*])*
-                const $Flag:tt = $value:expr;
+                $(pub $(($($fvis:tt)*))?)? const $Flag:tt = $value:expr;
             )*
         }
     ) => {
-        // struct
-        $(#[$outer])*
-        $vis struct $BitFlags($vis $T);
+        $crate::__paste! {
+            // a literal, as concatenated by `paste`, for the derive
+            #[doc = "The flags of [`" $BitFlags "`] by name, for foreign code by uniffi."]
+            #[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::cmp::PartialEq, ::core::cmp::Eq, $crate::__uniffi::Record)]
+            $vis struct [<$BitFlags Record>] {
+                $($vis [<$Flag:lower>]: bool,)*
+                // the bits of no flag set as a whole, e.g. unknown bits
+                $vis other_bits: $T,
+            }
 
-        impl $BitFlags {
+            impl ::core::convert::From<$BitFlags> for [<$BitFlags Record>] {
+                // as written by `Display`, so no bit is lost
+                fn from(flags: $BitFlags) -> Self {
+                    let mut rest = flags.0;
+                    $(
+                        let [<$Flag:lower>] = {
+                            let value = $crate::__tiny_bit_flags!(@value $BitFlags $Flag);
+                            let set = value != 0 && flags.0 & value == value;
+                            if set {
+                                rest &= !value;
+                            }
+                            set
+                        };
+                    )*
+                    Self { $([<$Flag:lower>],)* other_bits: rest }
+                }
+            }
+
+            impl ::core::convert::From<[<$BitFlags Record>]> for $BitFlags {
+                fn from(record: [<$BitFlags Record>]) -> Self {
+                    let mut bits = record.other_bits;
+                    $(
+                        if record.[<$Flag:lower>] {
+                            bits |= $crate::__tiny_bit_flags!(@value $BitFlags $Flag);
+                        }
+                    )*
+                    Self(bits)
+                }
+            }
+
+            $crate::__uniffi::custom_type!($BitFlags, [<$BitFlags Record>], {
+                lower: |flags| ::core::convert::From::from(flags),
+                try_lift: |record| ::core::result::Result::Ok(::core::convert::From::from(record)),
+            });
+        }
+    };
+}
+
+#[cfg(not(feature = "uniffi"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_uniffi {
+    ($($_:tt)*) => {
+        ::core::compile_error!(
+            "the `uniffi` option requires the `uniffi` feature of tiny-bit-flags"
+        );
+    };
+}
+
+#[cfg(feature = "utoipa")]
+#[doc(hidden)]
+pub use utoipa as __utoipa;
+
+#[cfg(feature = "utoipa")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_utoipa {
+    ([$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        impl $crate::__utoipa::PartialSchema for $BitFlags {
+            // the shape of the output of the `serde` mode
+            fn schema() -> $crate::__utoipa::openapi::RefOr<$crate::__utoipa::openapi::schema::Schema> {
+                use $crate::__utoipa::openapi::schema::{ArrayBuilder, ObjectBuilder, Type};
+                $crate::__tiny_bit_flags_utoipa!(@schema [$($opt)*] [$($opt)*] $BitFlags: $T)
+            }
+        }
+
+        impl $crate::__utoipa::ToSchema for $BitFlags {}
+    };
+
+    // an array of the names, as enum values
+    (@schema [serde = "list" $($_:tt)*] $opts:tt $BitFlags:ident: $T:ty) => {
+        ArrayBuilder::new()
+            .items(ObjectBuilder::new().schema_type(Type::String).enum_values(::core::option::Option::Some($BitFlags::FLAG_NAMES.iter().copied())))
+            .unique_items(true)
+            .into()
+    };
+    // an object of a boolean property for each flag
+    (@schema [serde = "map" $($_:tt)*] $opts:tt $BitFlags:ident: $T:ty) => {{
+        use $crate::__utoipa::openapi::schema::AdditionalProperties;
+        let mut object = ObjectBuilder::new().schema_type(Type::Object);
+        for &name in $BitFlags::FLAG_NAMES {
+            object = object.property(name, ObjectBuilder::new().schema_type(Type::Boolean));
+        }
+        if !$crate::__tiny_bit_flags!(@has serde_lenient $opts) {
+            object = object.additional_properties(::core::option::Option::Some(AdditionalProperties::FreeForm(false)));
+        }
+        object.into()
+    }};
+    (@schema [serde = "comma" $($_:tt)*] $opts:tt $BitFlags:ident: $T:ty) => {
+        ObjectBuilder::new().schema_type(Type::String).into()
+    };
+    // the little-endian bytes
+    (@schema [serde = "fixed" $($_:tt)*] $opts:tt $BitFlags:ident: $T:ty) => {
+        ArrayBuilder::new()
+            .items(ObjectBuilder::new().schema_type(Type::Integer).minimum(::core::option::Option::Some(0)).maximum(::core::option::Option::Some(255)))
+            .min_items(::core::option::Option::Some(::core::mem::size_of::<$T>()))
+            .max_items(::core::option::Option::Some(::core::mem::size_of::<$T>()))
+            .into()
+    };
+    (@schema [] $opts:tt $BitFlags:ident: $T:ty) => {
+        ::core::compile_error!("the `utoipa` option requires a `serde` mode, whose output the schema describes")
+    };
+    (@schema [$_:tt $($rest:tt)*] $opts:tt $BitFlags:ident: $T:ty) => {
+        $crate::__tiny_bit_flags_utoipa!(@schema [$($rest)*] $opts $BitFlags: $T)
+    };
+}
+
+#[cfg(not(feature = "utoipa"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_utoipa {
+    ($($_:tt)*) => {
+        ::core::compile_error!(
+            "the `utoipa` option requires the `utoipa` feature of tiny-bit-flags"
+        );
+    };
+}
+
+#[cfg(feature = "async-graphql")]
+#[doc(hidden)]
+pub use async_graphql as __async_graphql;
+
+#[cfg(feature = "async-graphql")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_async_graphql {
+    (
+        [$($opt:tt)*]
+        $vis:vis struct $BitFlags:ident: $T:ty {
             $(
-                // constant values
-                $(#[$inner $($args)*])*
-                $vis const $Flag: $T = $value;
+                $(#[$inner:ident $($args:tt)*])*
+                $(pub $(($($fvis:tt)*))?)? const $Flag:tt = $value:expr;
+            )*
+        }
+    ) => {
+        $crate::__paste! {
+            #[doc = "The flags of [`" $BitFlags "`], as the GraphQL enum of its list."]
+            #[derive(
+                ::core::fmt::Debug, ::core::clone::Clone, ::core::marker::Copy,
+                ::core::cmp::PartialEq, ::core::cmp::Eq, $crate::__async_graphql::Enum,
+            )]
+            #[graphql(crate = $crate::__async_graphql)]
+            #[allow(non_camel_case_types)]
+            $vis enum [<$BitFlags Flag>] {
+                $($Flag,)*
+            }
 
-                // methods
-                paste::paste! {
-                    $vis const fn [<is_ $Flag:lower>](&self) -> bool {
-                        self.0 & $value != 0
-                    }
-                    $vis const fn [<set_ $Flag:lower>](&mut self) {
-                        self.0 |= $value
+            impl $crate::__async_graphql::InputType for $BitFlags {
+                type RawValueType = Self;
+
+                fn type_name() -> ::std::borrow::Cow<'static, str> {
+                    <::std::vec::Vec<[<$BitFlags Flag>]> as $crate::__async_graphql::InputType>::type_name()
+                }
+
+                fn create_type_info(registry: &mut $crate::__async_graphql::registry::Registry) -> ::std::string::String {
+                    <::std::vec::Vec<[<$BitFlags Flag>]> as $crate::__async_graphql::InputType>::create_type_info(registry)
+                }
+
+                fn parse(
+                    value: ::core::option::Option<$crate::__async_graphql::Value>,
+                ) -> $crate::__async_graphql::InputValueResult<Self> {
+                    let flags = <::std::vec::Vec<[<$BitFlags Flag>]> as $crate::__async_graphql::InputType>::parse(value)
+                        .map_err($crate::__async_graphql::InputValueError::propagate)?;
+                    let mut bits = 0;
+                    for flag in flags {
+                        bits |= match flag {
+                            $([<$BitFlags Flag>]::$Flag => $crate::__tiny_bit_flags!(@value $BitFlags $Flag),)*
+                        };
                     }
-                    $vis const fn [<clear_ $Flag:lower>](&mut self) {
-                        self.0 &= !$value
+                    ::core::result::Result::Ok(Self(bits))
+                }
+
+                // the flags of all bits set, as written by `Display`, and
+                // unknown bits are left out
+                fn to_value(&self) -> $crate::__async_graphql::Value {
+                    let mut list = ::std::vec::Vec::new();
+                    $(
+                        let value = $crate::__tiny_bit_flags!(@value $BitFlags $Flag);
+                        if value != 0 && self.0 & value == value {
+                            list.push($crate::__async_graphql::Value::Enum($crate::__async_graphql::Name::new(::core::stringify!($Flag))));
+                        }
+                    )*
+                    $crate::__async_graphql::Value::List(list)
+                }
+
+                fn as_raw_value(&self) -> ::core::option::Option<&Self> {
+                    ::core::option::Option::Some(self)
+                }
+            }
+
+            impl $crate::__async_graphql::OutputType for $BitFlags {
+                fn type_name() -> ::std::borrow::Cow<'static, str> {
+                    <::std::vec::Vec<[<$BitFlags Flag>]> as $crate::__async_graphql::OutputType>::type_name()
+                }
+
+                fn create_type_info(registry: &mut $crate::__async_graphql::registry::Registry) -> ::std::string::String {
+                    <::std::vec::Vec<[<$BitFlags Flag>]> as $crate::__async_graphql::OutputType>::create_type_info(registry)
+                }
+
+                // unknown bits are an error, instead of left out silently
+                async fn resolve(
+                    &self,
+                    _ctx: &$crate::__async_graphql::ContextSelectionSet<'_>,
+                    field: &$crate::__async_graphql::Positioned<$crate::__async_graphql::parser::types::Field>,
+                ) -> $crate::__async_graphql::ServerResult<$crate::__async_graphql::Value> {
+                    use ::core::iter::Iterator as _;
+                    let unknown = self.0 & !$BitFlags::FLAG_VALUES.iter().fold(0, |all, &value| all | value);
+                    if unknown != 0 {
+                        return ::core::result::Result::Err($crate::__async_graphql::ServerError::new(
+                            ::std::format!("unknown bits {:#x} of `{}`", unknown, ::core::stringify!($BitFlags)),
+                            ::core::option::Option::Some(field.pos),
+                        ));
                     }
+                    ::core::result::Result::Ok($crate::__async_graphql::InputType::to_value(self))
                 }
-            )*
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "async-graphql"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_async_graphql {
+    ($($_:tt)*) => {
+        ::core::compile_error!(
+            "the `async_graphql` option requires the `async-graphql` feature of tiny-bit-flags"
+        );
+    };
+}
+
+#[cfg(feature = "redis")]
+#[doc(hidden)]
+pub use redis as __redis;
+
+#[cfg(feature = "redis")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_redis {
+    ([$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        impl $crate::__redis::ToRedisArgs for $BitFlags {
+            // the inner value as a decimal integer
+            fn write_redis_args<W: ?::core::marker::Sized + $crate::__redis::RedisWrite>(
+                &self,
+                out: &mut W,
+            ) {
+                $crate::__redis::ToRedisArgs::write_redis_args(&self.0, out)
+            }
+
+            fn describe_numeric_behavior(&self) -> $crate::__redis::NumericBehavior {
+                $crate::__redis::ToRedisArgs::describe_numeric_behavior(&self.0)
+            }
+        }
+
+        impl $crate::__redis::ToSingleRedisArg for $BitFlags {}
+
+        impl $crate::__redis::FromRedisValue for $BitFlags {
+            // unknown bits are an error, e.g. of a stale or foreign writer
+            fn from_redis_value(
+                v: $crate::__redis::Value,
+            ) -> ::core::result::Result<Self, $crate::__redis::ParsingError> {
+                use ::core::iter::Iterator as _;
+                let flags = Self(<$T as $crate::__redis::FromRedisValue>::from_redis_value(
+                    v,
+                )?);
+                let unknown =
+                    flags.0 & !Self::FLAG_VALUES.iter().fold(0, |all, &value| all | value);
+                if unknown != 0 {
+                    return ::core::result::Result::Err(
+                        ::std::format!(
+                            "unknown bits {:#x} of `{}`",
+                            unknown,
+                            ::core::stringify!($BitFlags),
+                        )
+                        .into(),
+                    );
+                }
+                ::core::result::Result::Ok(flags)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "redis"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_redis {
+    ($($_:tt)*) => {
+        ::core::compile_error!("the `redis` option requires the `redis` feature of tiny-bit-flags");
+    };
+}
+
+#[cfg(feature = "rusqlite")]
+#[doc(hidden)]
+pub use rusqlite as __rusqlite;
+
+#[cfg(feature = "rusqlite")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_rusqlite {
+    ([$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        const _: () = ::core::assert!(
+            <$T>::BITS <= 64,
+            ::core::concat!("the inner type of `", ::core::stringify!($BitFlags), "` is wider than an SQLite INTEGER"),
+        );
+
+        impl $crate::__rusqlite::types::ToSql for $BitFlags {
+            // the bits as an `i64`, so all 64 of them fit, e.g. of a `u64`
+            fn to_sql(&self) -> $crate::__rusqlite::Result<$crate::__rusqlite::types::ToSqlOutput<'_>> {
+                ::core::result::Result::Ok($crate::__rusqlite::types::ToSqlOutput::from(self.0 as i64))
+            }
+        }
+
+        impl $crate::__rusqlite::types::FromSql for $BitFlags {
+            // out of range if the bits don't fit, or unknown bits with
+            // `rusqlite_strict`
+            fn column_result(
+                value: $crate::__rusqlite::types::ValueRef<'_>,
+            ) -> $crate::__rusqlite::types::FromSqlResult<Self> {
+                use ::core::iter::Iterator as _;
+                let i = value.as_i64()?;
+                let flags = Self(i as $T);
+                if flags.0 as i64 != i {
+                    return ::core::result::Result::Err($crate::__rusqlite::types::FromSqlError::OutOfRange(i));
+                }
+                let unknown = flags.0 & !Self::FLAG_VALUES.iter().fold(0, |all, &value| all | value);
+                if $crate::__tiny_bit_flags!(@has rusqlite_strict [$($opt)*]) && unknown != 0 {
+                    return ::core::result::Result::Err($crate::__rusqlite::types::FromSqlError::Other(
+                        ::std::boxed::Box::new($crate::UnknownBitsError(())),
+                    ));
+                }
+                ::core::result::Result::Ok(flags)
+            }
         }
     };
 }
+
+#[cfg(not(feature = "rusqlite"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_rusqlite {
+    ($($_:tt)*) => {
+        ::core::compile_error!(
+            "the `rusqlite` option requires the `rusqlite` feature of tiny-bit-flags"
+        );
+    };
+}
+
+#[cfg(feature = "registry")]
+#[doc(hidden)]
+pub use inventory as __inventory;
+
+#[cfg(feature = "registry")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_registry {
+    ([$($opt:tt)*] $vis:vis struct $BitFlags:ident: $T:ty { $($body:tt)* }) => {
+        const _: () = {
+            // zero-extended, e.g. of a signed type
+            const VALUES: [u128; $BitFlags::NUM_FLAGS] = {
+                let mut values = [0; $BitFlags::NUM_FLAGS];
+                let mut i = 0;
+                while i < values.len() {
+                    values[i] =
+                        $BitFlags::FLAG_VALUES[i] as u128 & (u128::MAX >> (128 - <$T>::BITS));
+                    i += 1;
+                }
+                values
+            };
+
+            $crate::__inventory::submit! {
+                $crate::registry::FlagsType::__new(
+                    ::core::stringify!($BitFlags),
+                    ::core::module_path!(),
+                    <$T>::BITS,
+                    $BitFlags::FLAG_NAMES,
+                    &VALUES,
+                )
+            }
+        };
+    };
+}
+
+#[cfg(not(feature = "registry"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tiny_bit_flags_registry {
+    ($($_:tt)*) => {
+        ::core::compile_error!(
+            "the `registry` option requires the `registry` feature of tiny-bit-flags"
+        );
+    };
+}
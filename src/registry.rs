@@ -0,0 +1,136 @@
+//! A process-wide registry of the bit-flags structs generated by
+//! [`tiny_bit_flags!`](crate::tiny_bit_flags) with the `registry` option,
+//! so that tools can decode a flags integer knowing only the type name,
+//! e.g. to pretty-print the fields of a crash dump.
+//!
+//! The structs are collected at link time by the `inventory` crate, from
+//! all crates of the program:
+//!
+//! ```rust
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[tiny_bit_flags(registry)]
+//!     struct PrimFlags: u32 {
+//!         const WRITABLE   = 0b00000001;
+//!         const EXECUTABLE = 0b00000010;
+//!     }
+//! }
+//!
+//! let prim = tiny_bit_flags::registry::find("PrimFlags").unwrap();
+//! assert_eq!(prim.flag_names(), ["WRITABLE", "EXECUTABLE"]);
+//! assert_eq!(prim.decode(0b111).to_string(), "WRITABLE | EXECUTABLE | 0x4");
+//!
+//! tiny_bit_flags::tiny_bit_flags! {
+//!     #[tiny_bit_flags(registry)]
+//!     struct SignedFlags: i8 {
+//!         const NEGATIVE = i8::MIN;
+//!     }
+//! }
+//!
+//! let signed = tiny_bit_flags::registry::find("SignedFlags").unwrap();
+//! assert_eq!((signed.bits(), signed.flag_values()), (8, &[0x80][..]));
+//! assert_eq!(signed.decode(-1i8 as u128).to_string(), "NEGATIVE | 0x7f");
+//! ```
+
+use core::fmt;
+
+/// A registered bit-flags struct, with its flags.
+#[derive(Debug)]
+pub struct FlagsType {
+    name: &'static str,
+    module_path: &'static str,
+    bits: u32,
+    flag_names: &'static [&'static str],
+    flag_values: &'static [u128],
+}
+
+crate::__inventory::collect!(FlagsType);
+
+impl FlagsType {
+    // the values as of the inner type, zero-extended
+    #[doc(hidden)]
+    pub const fn __new(
+        name: &'static str,
+        module_path: &'static str,
+        bits: u32,
+        flag_names: &'static [&'static str],
+        flag_values: &'static [u128],
+    ) -> Self {
+        FlagsType {
+            name,
+            module_path,
+            bits,
+            flag_names,
+            flag_values,
+        }
+    }
+
+    /// The name of the struct.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The module path of the struct, as by `module_path!()`.
+    pub fn module_path(&self) -> &'static str {
+        self.module_path
+    }
+
+    /// The width of the inner type, in bits.
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// Names of flags, in declaration order.
+    pub fn flag_names(&self) -> &'static [&'static str] {
+        self.flag_names
+    }
+
+    /// Values of flags, in declaration order, zero-extended from the inner
+    /// type.
+    pub fn flag_values(&self) -> &'static [u128] {
+        self.flag_values
+    }
+
+    /// The flags of the bits as written by `Display` of the struct, e.g.
+    /// `WRITABLE | 0x4`, with the bits out of the inner type dropped.
+    pub fn decode(&self, bits: u128) -> impl fmt::Display + '_ {
+        Decoded(self, bits & (u128::MAX >> (128 - self.bits)))
+    }
+}
+
+struct Decoded<'a>(&'a FlagsType, u128);
+
+impl fmt::Display for Decoded<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Decoded(ty, bits) = *self;
+        let mut rest = bits;
+        let mut sep = "";
+        for (&name, &value) in ty.flag_names.iter().zip(ty.flag_values) {
+            if bits & value == value && rest & value != 0 {
+                f.write_str(sep)?;
+                f.write_str(name)?;
+                rest &= !value;
+                sep = " | ";
+            }
+        }
+        if rest != 0 {
+            write!(f, "{sep}{rest:#x}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Iterator over all registered structs, in no particular order.
+pub fn iter() -> impl Iterator<Item = &'static FlagsType> {
+    crate::__inventory::iter::<FlagsType>.into_iter()
+}
+
+/// The registered struct of the name, or the first one found if several
+/// modules have a struct of the name; see [`find_in`] for those.
+pub fn find(name: &str) -> Option<&'static FlagsType> {
+    iter().find(|ty| ty.name == name)
+}
+
+/// The registered struct of the name in the module.
+pub fn find_in(module_path: &str, name: &str) -> Option<&'static FlagsType> {
+    iter().find(|ty| ty.module_path == module_path && ty.name == name)
+}